@@ -2,104 +2,102 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
-use std::{fs, process};
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use advent_of_code::cache::{sha256, InputCache};
+use advent_of_code::client::Client;
+use advent_of_code::runner::parse_year_and_day;
+use advent_of_code::{input_path, write_atomic};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: u16,
+    /// Download the input again, even if it is cached.
+    force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
-    })
+    let year: Option<u16> = args.opt_value_from_str(["-y", "--year"])?;
+    let force = args.contains("--force");
+    let (year, day) = match year {
+        Some(year) => (year, args.free_from_str()?),
+        None => parse_year_and_day(&mut args)?,
+    };
+    Ok(Args { day, year, force })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("Failed to download input: {}", message);
+    process::exit(1);
 }
 
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+fn write_input(path: &Path, input: &str) {
+    match write_atomic(path, input) {
+        Ok(_) => {
+            println!("---");
+            println!("🎄 Successfully wrote input to \"{}\".", path.display());
+        }
+        Err(e) => {
+            eprintln!("Could not write input to \"{}\": {}", path.display(), e);
+            process::exit(1);
+        }
+    }
 }
 
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
         }
     };
+    let path = input_path("inputs", args.year, args.day);
+    let cache = InputCache::default();
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
-    }
-
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
-
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
+    if let Some(cached) = cache.get(args.year, args.day).filter(|_| !args.force) {
+        let fetched = cached.metadata.fetched_date();
+        match fs::read_to_string(&path) {
+            Ok(input) if sha256(&input) == cached.metadata.sha256 => {
+                println!(
+                    "Input for day {}, {} was already downloaded on {} to \"{}\".",
+                    args.day,
+                    args.year,
+                    fetched,
+                    path.display()
+                );
+                println!("Pass --force to download it again.");
+            }
+            Ok(input) if !input.trim().is_empty() => {
+                println!(
+                    "\"{}\" differs from the input downloaded on {}, keeping it.",
+                    path.display(),
+                    fetched
+                );
+                println!("Pass --force to download it again and replace it.");
+            }
+            _ => {
+                println!(
+                    "Using the input for day {}, {} downloaded on {}.",
+                    args.day, args.year, fetched
+                );
+                write_input(&path, &cached.input);
             }
         }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
-        }
+        return;
     }
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
-        }
-        Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
-        }
+    let client = Client::from_env().unwrap_or_else(|e| exit_with_error(e));
+    println!("Downloading input for day {}, {}...", args.day, args.year);
+    let input = client
+        .fetch_input(args.year, args.day)
+        .unwrap_or_else(|e| exit_with_error(e));
+
+    if let Err(e) = cache.store(args.year, args.day, &input) {
+        eprintln!("Could not cache the input: {}", e);
     }
+    write_input(&path, &input);
 }
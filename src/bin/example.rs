@@ -0,0 +1,145 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::ffi::OsString;
+use std::fs;
+use std::process;
+
+use advent_of_code::client::Client;
+use advent_of_code::puzzle::{code_blocks, example_answers, pick_example};
+use advent_of_code::runner::{example_path, load_example, parse_year_and_day};
+use advent_of_code::write_atomic;
+
+struct Args {
+    year: u16,
+    day: u8,
+    /// A saved puzzle description to read instead of fetching it.
+    html: Option<OsString>,
+    /// Name of the example file.
+    name: String,
+    /// Number of the code block to use, counting from 1, instead of guessing.
+    block: Option<usize>,
+    /// Put the emphasized answers of the description into the header.
+    answers: bool,
+    /// Replace an example that already has an input.
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let html = args.opt_value_from_os_str("--html", |path| {
+        Ok::<_, std::convert::Infallible>(path.to_os_string())
+    })?;
+    let name = args
+        .opt_value_from_str("--name")?
+        .unwrap_or_else(|| "example".to_string());
+    let block = args.opt_value_from_str("--block")?;
+    let answers = args.contains("--answers");
+    let force = args.contains("--force");
+    let (year, day) = parse_year_and_day(&mut args)?;
+    Ok(Args {
+        year,
+        day,
+        html,
+        name,
+        block,
+        answers,
+        force,
+    })
+}
+
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
+            exit_with_error("Need to specify a day (as integer). example: `cargo example 7` or `cargo example 2022 7`")
+        }
+        Err(e) => exit_with_error(format!("Failed to process arguments: {}", e)),
+    };
+
+    let html = match &args.html {
+        Some(path) => fs::read_to_string(path).unwrap_or_else(|e| {
+            exit_with_error(format!(
+                "Could not read \"{}\": {}",
+                path.to_string_lossy(),
+                e
+            ))
+        }),
+        None => {
+            println!(
+                "Fetching the description of day {}, {}...",
+                args.day, args.year
+            );
+            Client::from_env_or_anonymous()
+                .fetch_puzzle(args.year, args.day)
+                .unwrap_or_else(|e| {
+                    exit_with_error(format!("Failed to fetch the description: {}", e))
+                })
+        }
+    };
+
+    let blocks = code_blocks(&html);
+    let index = match args.block {
+        Some(block @ 1..) if block <= blocks.len() => block - 1,
+        Some(block) => exit_with_error(format!(
+            "There is no code block {}, the description has {}.",
+            block,
+            blocks.len()
+        )),
+        None => pick_example(&blocks).unwrap_or_else(|| {
+            exit_with_error("The description has no <pre><code> blocks to take an example from.")
+        }),
+    };
+
+    let path = example_path(args.year, args.day, &args.name);
+    if path.exists() && !args.force {
+        match load_example(args.year, args.day, &args.name) {
+            Ok(example) if example.input.trim().is_empty() => {}
+            _ => exit_with_error(format!(
+                "\"{}\" already has an input. Pass --force to replace it.",
+                path.display()
+            )),
+        }
+    }
+
+    let answers = if args.answers {
+        example_answers(&html)
+    } else {
+        Default::default()
+    };
+    let mut text = String::new();
+    for part in 1..=2 {
+        match answers.get(&part) {
+            Some(answer) => text.push_str(&format!("{}: {}\n", part, answer)),
+            None => text.push_str(&format!("{}:\n", part)),
+        }
+    }
+    text.push_str("---\n");
+    text.push_str(&blocks[index].text);
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+
+    if let Err(e) = write_atomic(&path, &text) {
+        exit_with_error(format!("Could not write \"{}\": {}", path.display(), e));
+    }
+    println!(
+        "Took code block {} of {} from part {}, pass --block <n> to take another.",
+        index + 1,
+        blocks.len(),
+        blocks[index].part
+    );
+    for part in 1..=2 {
+        if let Some(answer) = answers.get(&part) {
+            println!("Expecting {} for part {}.", answer, part);
+        }
+    }
+    println!("---");
+    println!("🎄 Wrote example to \"{}\".", path.display());
+}
@@ -3,120 +3,662 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
+use advent_of_code::runner::parse_year_and_day;
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
-}
+/// Directory with the module templates, in which `{{year}}`, `{{day}}` and `{{returns}}` are
+/// replaced.
+const TEMPLATES_DIR: &str = "templates";
+const DEFAULT_TEMPLATE: &str = "default";
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+/// Lists the module of each year.
+const YEARS_REGISTRY_PATH: &str = "src/days/mod.rs";
+
+/// `path` within the crate rather than the working directory, so scaffolding works from
+/// anywhere in it.
+fn crate_path(path: &str) -> String {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(path)
+        .display()
+        .to_string()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_TEMPLATE: &str = "1:\n2:\n---\n";
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
+/// Registry of a year without days yet.
+const YEAR_TEMPLATE: &str = r###"/*
+ * Registry of the solved days of {{year}}. Add new days here after scaffolding them.
+ */
+use super::Day;
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
+const YEAR: u16 = {{year}};
 
-    #[test]
-    #[ignore]
-    fn test_solutions() {
-        let input = advent_of_code::read_file("inputs", DAY);
-        assert_eq!(part_one(&input), None);
-        assert_eq!(part_two(&input), None);
+pub static DAYS: &[Day] = &[
+];
+"###;
+
+/// Types that `Answer` can be created from.
+const RETURN_TYPES: &[&str] = &["u32", "u64", "usize", "i32", "i64", "isize", "String"];
+
+struct Args {
+    year: u16,
+    day: u8,
+    template: String,
+    returns: String,
+    /// Overwrite an existing module.
+    force: bool,
+    /// Back up and replace all existing files of the day, including its input and example.
+    reset: bool,
+    /// Only print what would be done.
+    dry_run: bool,
+}
+
+fn parse_returns(value: &str) -> Result<String, String> {
+    if RETURN_TYPES.contains(&value) {
+        Ok(value.to_string())
+    } else {
+        Err(format!(
+            "\"{}\" is not a supported answer type, expected one of {}",
+            value,
+            RETURN_TYPES.join(", ")
+        ))
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    let template = args
+        .opt_value_from_str("--template")?
+        .unwrap_or_else(|| DEFAULT_TEMPLATE.to_string());
+    let returns = args
+        .opt_value_from_fn("--returns", parse_returns)?
+        .unwrap_or_else(|| "u32".to_string());
+    let force = args.contains("--force");
+    let reset = args.contains("--reset");
+    let dry_run = args.contains("--dry-run");
+    let (year, day) = parse_year_and_day(&mut args)?;
+
+    Ok(Args {
+        year,
+        day,
+        template,
+        returns,
+        force,
+        reset,
+        dry_run,
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+fn available_templates() -> Vec<String> {
+    let mut names = fs::read_dir(crate_path(TEMPLATES_DIR))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    match path.extension() {
+                        Some(extension) if extension == "rs" => {
+                            Some(path.file_stem()?.to_str()?.to_string())
+                        }
+                        _ => None,
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    names.sort_unstable();
+    names
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn render(template: &str, year: u16, day: u8, returns: &str) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{returns}}", returns)
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
+/// Adds `day` to the module declarations and the `DAYS` list of a year's registry, both kept
+/// in day order. Returns `None` if the day is already registered.
+fn register_day(registry: &str, day: u8) -> Result<Option<String>, String> {
+    let module = format!("day{:02}", day);
+    let mod_line = format!("pub mod {};", module);
+    if registry.lines().any(|line| line.trim() == mod_line) {
+        return Ok(None);
+    }
+
+    let mut lines = registry.lines().map(String::from).collect::<Vec<_>>();
+
+    let mod_lines = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod day"))
+        .collect::<Vec<_>>();
+    match mod_lines.iter().find(|(_, line)| **line > mod_line) {
+        Some(&(i, _)) => lines.insert(i, mod_line),
+        None => match mod_lines.last() {
+            Some(&(i, _)) => lines.insert(i + 1, mod_line),
+            // the first day of a year goes right before its `YEAR`.
+            None => {
+                let i = lines
+                    .iter()
+                    .position(|line| line.starts_with("const YEAR"))
+                    .ok_or("found no `pub mod dayNN;` declarations or `YEAR`")?;
+                lines.splice(i..i, [mod_line, String::new()]);
+            }
+        },
+    }
+
+    let list_start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static DAYS"))
+        .ok_or("found no `DAYS` list")?;
+    if let Some(start) = lines[list_start].strip_suffix("];") {
+        let start = start.to_string();
+        lines.splice(list_start..=list_start, [start, "];".to_string()]);
+    }
+    let list_end = list_start
+        + lines[list_start..]
+            .iter()
+            .position(|line| line.trim() == "];")
+            .ok_or("found no end of the `DAYS` list")?;
+    // entries start at their `Day {` line, so insert before the first one of a later day.
+    let entry_day = |i: usize| {
+        lines[i + 1..list_end]
+            .iter()
+            .find_map(|line| line.trim().strip_prefix("day: "))
+            .and_then(|rest| rest.trim_end_matches(',').parse::<u8>().ok())
+    };
+    let entry_index = (list_start..list_end)
+        .find(|&i| lines[i].trim() == "Day {" && entry_day(i).is_some_and(|other| other > day))
+        .unwrap_or(list_end);
+    let entry = [
+        "    Day {".to_string(),
+        "        year: YEAR,".to_string(),
+        format!("        day: {},", day),
+        format!("        solution: &{}::Solver,", module),
+        "        slow: false,".to_string(),
+        "    },".to_string(),
+    ];
+    lines.splice(entry_index..entry_index, entry);
+
+    let mut registry = lines.join("\n");
+    registry.push('\n');
+    Ok(Some(registry))
+}
+
+/// Adds the module of `year` to the module declarations and the `YEARS` list of the top
+/// registry, both kept in year order. Returns `None` if the year is already registered.
+fn register_year(registry: &str, year: u16) -> Result<Option<String>, String> {
+    let mod_line = format!("pub mod y{};", year);
+    if registry.lines().any(|line| line.trim() == mod_line) {
+        return Ok(None);
+    }
+
+    let mut lines = registry.lines().map(String::from).collect::<Vec<_>>();
+    let mod_index = match lines
+        .iter()
+        .position(|line| line.starts_with("pub mod y") && *line > mod_line)
+    {
+        Some(i) => i,
+        None => {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("pub mod y"))
+                .ok_or("found no `pub mod yNNNN;` declarations")?
+                + 1
         }
     };
+    lines.insert(mod_index, mod_line);
 
-    let day_padded = format!("{:02}", day);
+    let list_start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static YEARS"))
+        .ok_or("found no `YEARS` list")?;
+    let list_end = list_start
+        + lines[list_start..]
+            .iter()
+            .position(|line| line.trim_end().ends_with("];"))
+            .ok_or("found no end of the `YEARS` list")?;
+    let list = lines[list_start..=list_end].join(" ");
+    let (declaration, items) = list.split_once("= &[").ok_or("found no `YEARS` list")?;
+    let mut items = items
+        .trim_end()
+        .trim_end_matches("];")
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect::<Vec<_>>();
+    items.push(format!("y{}::DAYS", year));
+    items.sort_unstable();
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    // the list stays on one line until it gets too long, as rustfmt would have it.
+    let one_line = format!("{}= &[{}];", declaration, items.join(", "));
+    let list = if one_line.len() <= 100 {
+        vec![one_line]
+    } else {
+        std::iter::once(format!("{}= &[", declaration))
+            .chain(items.iter().map(|item| format!("    {},", item)))
+            .chain(std::iter::once("];".to_string()))
+            .collect()
+    };
+    lines.splice(list_start..=list_end, list);
+
+    let mut registry = lines.join("\n");
+    registry.push('\n');
+    Ok(Some(registry))
+}
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+/// Writes `registry` to `path` if it changed, or says what would be written.
+fn update_registry(
+    path: &str,
+    registered: Result<Option<String>, String>,
+    what: &str,
+    dry_run: bool,
+) {
+    match registered {
+        Ok(None) => println!("{} is already registered in \"{}\"", what, path),
+        Ok(Some(_)) if dry_run => println!("Would register {} in \"{}\"", what, path),
+        Ok(Some(registry)) => match fs::write(path, registry) {
+            Ok(_) => println!("Registered {} in \"{}\"", what, path),
+            Err(e) => {
+                eprintln!("Failed to register {}: {}", what, e);
+                process::exit(1);
+            }
+        },
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("Failed to register {} in \"{}\": {}", what, path, e);
             process::exit(1);
         }
-    };
+    }
+}
+
+/// A file that scaffolding generates.
+struct Artifact {
+    kind: &'static str,
+    path: String,
+    contents: String,
+    /// Source files may be overwritten with `--force`. Inputs and examples hold data that
+    /// can't be generated again, so they're only replaced with `--reset`, which backs them up.
+    is_source: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    Create,
+    Keep,
+    Overwrite,
+    Reset { backup: String },
+}
+
+fn plan(artifact: &Artifact, exists: bool, args: &Args) -> Action {
+    match exists {
+        false => Action::Create,
+        true if args.reset => Action::Reset {
+            backup: backup_path(&artifact.path, |path| Path::new(path).exists()),
+        },
+        true if args.force && artifact.is_source => Action::Overwrite,
+        true => Action::Keep,
+    }
+}
+
+/// The first of `<path>.bak`, `<path>.bak.1`, … for which `exists` is false.
+fn backup_path(path: &str, exists: impl Fn(&str) -> bool) -> String {
+    let first = format!("{}.bak", path);
+    std::iter::once(first.clone())
+        .chain((1..).map(|n| format!("{}.{}", first, n)))
+        .find(|candidate| !exists(candidate))
+        .unwrap()
+}
+
+fn describe(action: &Action, artifact: &Artifact, dry_run: bool) -> String {
+    let (kind, path) = (artifact.kind, &artifact.path);
+    let empty = if artifact.is_source { "" } else { "empty " };
+    match (action, dry_run) {
+        (Action::Create, false) => format!("Created {}{} file \"{}\"", empty, kind, path),
+        (Action::Create, true) => format!("Would create {}{} file \"{}\"", empty, kind, path),
+        (Action::Keep, _) => format!(
+            "{} existing {} file \"{}\", pass {} to replace it",
+            if dry_run { "Would keep" } else { "Kept" },
+            kind,
+            path,
+            if artifact.is_source {
+                "--force or --reset"
+            } else {
+                "--reset"
+            }
+        ),
+        (Action::Overwrite, false) => format!("Overwrote {} file \"{}\"", kind, path),
+        (Action::Overwrite, true) => format!("Would overwrite {} file \"{}\"", kind, path),
+        (Action::Reset { backup }, false) => format!(
+            "Reset {} file \"{}\", the old one is in \"{}\"",
+            kind, path, backup
+        ),
+        (Action::Reset { backup }, true) => format!(
+            "Would reset {} file \"{}\", keeping the old one in \"{}\"",
+            kind, path, backup
+        ),
+    }
+}
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+fn apply(action: &Action, artifact: &Artifact) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true);
+    match action {
+        Action::Keep => return Ok(()),
+        Action::Create => {
+            if let Some(dir) = Path::new(&artifact.path).parent() {
+                fs::create_dir_all(dir)?;
+            }
+            options.create_new(true);
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
+        Action::Overwrite => {
+            options.truncate(true);
+        }
+        Action::Reset { backup } => {
+            fs::copy(&artifact.path, backup)?;
+            options.truncate(true);
         }
     }
+    options
+        .open(&artifact.path)?
+        .write_all(artifact.contents.as_bytes())
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
+            eprintln!(
+                "Need to specify a day (as integer). example: `cargo scaffold 7` or `cargo scaffold 2022 7`"
+            );
+            process::exit(1);
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let (year, day) = (args.year, args.day);
+
+    let template_path = crate_path(&format!("{}/{}.rs", TEMPLATES_DIR, args.template));
+    let template = match fs::read_to_string(&template_path) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read template \"{}\": {}", template_path, e);
+            eprintln!("Available templates: {}", available_templates().join(", "));
             process::exit(1);
         }
+    };
+
+    let day_padded = format!("{:02}", day);
+
+    let input_path = crate_path(&format!("src/inputs/{}/{}.txt", year, day_padded));
+    let example_path = crate_path(&format!("src/examples/{}/{}/example.txt", year, day_padded));
+    let module_path = crate_path(&format!("src/days/y{}/day{}.rs", year, day_padded));
+    let registry_path = crate_path(&format!("src/days/y{}/mod.rs", year));
+
+    let artifacts = [
+        Artifact {
+            kind: "module",
+            path: module_path,
+            contents: render(&template, year, day, &args.returns),
+            is_source: true,
+        },
+        Artifact {
+            kind: "input",
+            path: input_path,
+            contents: String::new(),
+            is_source: false,
+        },
+        Artifact {
+            kind: "example",
+            path: example_path,
+            contents: EXAMPLE_TEMPLATE.to_string(),
+            is_source: false,
+        },
+    ];
+
+    for artifact in &artifacts {
+        let action = plan(artifact, Path::new(&artifact.path).exists(), &args);
+        if !args.dry_run {
+            if let Err(e) = apply(&action, artifact) {
+                eprintln!(
+                    "Failed to write {} file \"{}\": {}",
+                    artifact.kind, artifact.path, e
+                );
+                process::exit(1);
+            }
+        }
+        println!("{}", describe(&action, artifact, args.dry_run));
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    // a new year starts with an empty registry, which is then registered itself.
+    let registry = match fs::read_to_string(&registry_path) {
+        Ok(registry) => registry,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let registry = render(YEAR_TEMPLATE, year, day, &args.returns);
+            if !args.dry_run {
+                if let Err(e) = fs::write(&registry_path, &registry) {
+                    eprintln!("Failed to create \"{}\": {}", registry_path, e);
+                    process::exit(1);
+                }
+            }
+            let years_registry_path = crate_path(YEARS_REGISTRY_PATH);
+            let registered = fs::read_to_string(&years_registry_path)
+                .map_err(|e| e.to_string())
+                .and_then(|registry| register_year(&registry, year));
+            let what = format!("year {}", year);
+            update_registry(&years_registry_path, registered, &what, args.dry_run);
+            registry
         }
         Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+            eprintln!("Failed to read \"{}\": {}", registry_path, e);
             process::exit(1);
         }
+    };
+    let registered = register_day(&registry, day);
+    if let Err(e) = &registered {
+        eprintln!("Failed to register the day in \"{}\": {}", registry_path, e);
+        eprintln!("Add `pub mod day{};` and its `Solver` by hand.", day_padded);
+        process::exit(1);
     }
-
-    println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+    update_registry(
+        &registry_path,
+        registered,
+        &format!("day {}", day),
+        args.dry_run,
     );
+
+    if !args.dry_run {
+        println!("---");
+        println!(
+            "🎄 Type `cargo solve {} {}` to run your solution.",
+            year, &day_padded
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "\
+use super::Day;
+
+pub mod day01;
+pub mod day03;
+
+const YEAR: u16 = 2022;
+
+pub static DAYS: &[Day] = &[
+    Day {
+        year: YEAR,
+        day: 1,
+        solution: &day01::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 3,
+        solution: &day03::Solver,
+        slow: true,
+    },
+];
+";
+
+    #[test]
+    fn test_register_day() {
+        assert_eq!(
+            register_day(REGISTRY, 2),
+            Ok(Some(
+                "\
+use super::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+const YEAR: u16 = 2022;
+
+pub static DAYS: &[Day] = &[
+    Day {
+        year: YEAR,
+        day: 1,
+        solution: &day01::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 2,
+        solution: &day02::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 3,
+        solution: &day03::Solver,
+        slow: true,
+    },
+];
+"
+                .to_string()
+            ))
+        );
+
+        let registry = register_day(REGISTRY, 12).unwrap().unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day12;\n"));
+        assert!(registry.ends_with("solution: &day12::Solver,\n        slow: false,\n    },\n];\n"));
+
+        assert_eq!(register_day(REGISTRY, 3), Ok(None));
+        assert!(register_day("pub static DAYS: &[Day] = &[];\n", 1).is_err());
+
+        let registry = register_day(&render(YEAR_TEMPLATE, 2023, 1, "u32"), 1)
+            .unwrap()
+            .unwrap();
+        assert!(registry.contains(
+            "use super::Day;\n\npub mod day01;\n\nconst YEAR: u16 = 2023;\n\npub static DAYS: &[Day] = &[\n    Day {\n        year: YEAR,\n        day: 1,\n"
+        ));
+        assert!(register_day("pub mod day01;\n\npub static DAYS: &[Day] = &[];\n", 2)
+            .unwrap()
+            .unwrap()
+            .ends_with("&[\n    Day {\n        year: YEAR,\n        day: 2,\n        solution: &day02::Solver,\n        slow: false,\n    },\n];\n"));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let registry = "pub mod y2022;\n\npub static YEARS: &[&[Day]] = &[y2022::DAYS];\n";
+        assert_eq!(
+            register_year(registry, 2015),
+            Ok(Some(
+                "pub mod y2015;\npub mod y2022;\n\npub static YEARS: &[&[Day]] = &[y2015::DAYS, y2022::DAYS];\n"
+                    .to_string()
+            ))
+        );
+        assert_eq!(register_year(registry, 2022), Ok(None));
+
+        let mut registry = registry.to_string();
+        for year in 2015..=2021 {
+            registry = register_year(&registry, year).unwrap().unwrap();
+        }
+        assert!(registry.contains("pub mod y2021;\npub mod y2022;\n"));
+        assert!(registry.ends_with(
+            "pub static YEARS: &[&[Day]] = &[\n    y2015::DAYS,\n    y2016::DAYS,\n    y2017::DAYS,\n    y2018::DAYS,\n    y2019::DAYS,\n    y2020::DAYS,\n    y2021::DAYS,\n    y2022::DAYS,\n];\n"
+        ));
+        assert!(register_year(&registry, 2023)
+            .unwrap()
+            .unwrap()
+            .contains("    y2022::DAYS,\n    y2023::DAYS,\n];\n"));
+        assert!(register_year("pub static YEARS: &[&[Day]] = &[];\n", 2022).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        assert!(render(YEAR_TEMPLATE, 2023, 7, "u64").contains("const YEAR: u16 = 2023;\n"));
+        assert_eq!(
+            render("fn part_one() -> Option<{{returns}}>", 2023, 7, "String"),
+            "fn part_one() -> Option<String>"
+        );
+    }
+
+    #[test]
+    fn test_parse_returns() {
+        assert_eq!(parse_returns("i64"), Ok("i64".to_string()));
+        assert!(parse_returns("f32").is_err());
+    }
+
+    #[test]
+    fn test_plan() {
+        let args = |force, reset| Args {
+            year: 2022,
+            day: 7,
+            template: DEFAULT_TEMPLATE.to_string(),
+            returns: "u32".to_string(),
+            force,
+            reset,
+            dry_run: false,
+        };
+        let artifact = |is_source| Artifact {
+            kind: "input",
+            path: "/nonexistent/07.txt".to_string(),
+            contents: String::new(),
+            is_source,
+        };
+
+        assert_eq!(
+            plan(&artifact(false), false, &args(true, true)),
+            Action::Create
+        );
+        assert_eq!(
+            plan(&artifact(true), true, &args(false, false)),
+            Action::Keep
+        );
+        assert_eq!(
+            plan(&artifact(true), true, &args(true, false)),
+            Action::Overwrite
+        );
+        assert_eq!(
+            plan(&artifact(false), true, &args(true, false)),
+            Action::Keep
+        );
+        assert_eq!(
+            plan(&artifact(false), true, &args(true, true)),
+            Action::Reset {
+                backup: "/nonexistent/07.txt.bak".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_backup_path() {
+        assert_eq!(backup_path("07.txt", |_| false), "07.txt.bak");
+        let taken = ["07.txt.bak", "07.txt.bak.1"];
+        assert_eq!(
+            backup_path("07.txt", |path| taken.contains(&path)),
+            "07.txt.bak.2"
+        );
+    }
 }
@@ -0,0 +1,122 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::process;
+use std::sync::Arc;
+
+use advent_of_code::client::{Client, SubmitOutcome};
+use advent_of_code::runner::{parse_part, parse_year_and_day, run_part, warn_about_input, Options};
+use advent_of_code::submissions::{check_answer, Submission, SubmissionLog};
+use advent_of_code::{days, try_read_file, ANSI_BOLD, ANSI_RESET};
+
+struct Args {
+    year: u16,
+    day: u8,
+    part: u8,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let (year, day) = parse_year_and_day(&mut args)?;
+    Ok(Args {
+        year,
+        day,
+        part: args.free_from_fn(parse_part)?,
+    })
+}
+
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
+            exit_with_error("Need to specify a day and part. example: `cargo submit 7 1` or `cargo submit 2022 7 1`")
+        }
+        Err(e) => exit_with_error(format!("Failed to process arguments: {}", e)),
+    };
+    let Some(day) = days::get(args.year, args.day) else {
+        exit_with_error(format!(
+            "Day {} of {} is not registered in src/days/y{}/mod.rs",
+            args.day, args.year, args.year
+        ));
+    };
+
+    let input = try_read_file("inputs", day.year, day.day)
+        .unwrap_or_else(|e| exit_with_error(format!("{} day {:02}: {}", day.year, day.day, e)));
+    warn_about_input(day.year, day.day, &input);
+
+    let result = run_part(day, args.part, &Arc::from(input), &Options::default());
+    let answer = match (result.answer, result.error) {
+        (_, Some(e)) => exit_with_error(format!(
+            "{} day {:02} part {}: {}",
+            day.year, day.day, args.part, e
+        )),
+        (None, None) => exit_with_error(format!(
+            "{} day {:02} part {} has no answer to submit.",
+            day.year, day.day, args.part
+        )),
+        (Some(answer), None) => answer.to_string(),
+    };
+    if answer.contains('\n') {
+        exit_with_error(format!(
+            "The answer spans several lines, read it and submit it by hand:\n{}",
+            answer
+        ));
+    }
+
+    let log = SubmissionLog::default();
+    let submissions = log.load().unwrap_or_else(|e| {
+        exit_with_error(format!(
+            "Could not read the submission log \"{}\": {}",
+            log.path().display(),
+            e
+        ))
+    });
+    if let Some(reason) = check_answer(&submissions, day.year, day.day, args.part, &answer) {
+        exit_with_error(format!("Not submitting: {}.", reason));
+    }
+
+    let client = Client::from_env()
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to submit answer: {}", e)));
+    println!(
+        "Submitting {} for day {} of {}, part {}...",
+        answer, day.day, day.year, args.part
+    );
+    let outcome = client
+        .submit_answer(day.year, day.day, args.part, &answer)
+        .unwrap_or_else(|e| exit_with_error(format!("Failed to submit answer: {}", e)));
+
+    let submission = Submission::new(day.year, day.day, args.part, &answer, outcome.clone());
+    if let Err(e) = log.append(&submission) {
+        eprintln!(
+            "Could not record the submission in \"{}\": {}",
+            log.path().display(),
+            e
+        );
+    }
+
+    println!("---");
+    match outcome {
+        SubmitOutcome::Correct => {
+            println!("🎄 {}That's the right answer!{}", ANSI_BOLD, ANSI_RESET);
+            return;
+        }
+        SubmitOutcome::Incorrect => println!("That's not the right answer."),
+        SubmitOutcome::TooHigh => println!("That's not the right answer, it's too high."),
+        SubmitOutcome::TooLow => println!("That's not the right answer, it's too low."),
+        SubmitOutcome::RateLimited { wait } => println!(
+            "You submitted an answer too recently, wait {} before trying again.",
+            wait.as_deref().unwrap_or("a bit")
+        ),
+        SubmitOutcome::WrongLevel => {
+            println!("That part is already solved, or its first part isn't yet.")
+        }
+        SubmitOutcome::Unknown { message } => println!("Unexpected response: {}", message),
+    }
+    process::exit(1);
+}
@@ -0,0 +1,235 @@
+/*
+ * This file contains template code.
+ * Downloaded puzzle inputs, kept per year and day along with a hash and the time of download.
+ *
+ * Each input is stored in `.cache/inputs/<year>/<day>.txt`, next to a `<day>.meta` file with
+ * the fields of its [`Metadata`], e.g.:
+ *
+ * year: 2022
+ * day: 1
+ * fetched: 1669870800
+ * sha256: 4f8d…
+ */
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sha2::{Digest, Sha256};
+
+use crate::write_atomic;
+
+/// Directory of the cache, relative to the crate.
+pub const CACHE_DIR: &str = ".cache/inputs";
+
+/// What is known about a downloaded input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub year: u16,
+    pub day: u8,
+    /// Time of the download, in seconds since the Unix epoch.
+    pub fetched: u64,
+    /// SHA-256 of the input, as lowercase hex.
+    pub sha256: String,
+}
+
+impl Metadata {
+    pub fn parse(text: &str) -> Option<Self> {
+        let field = |name: &str| {
+            text.lines().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                (key.trim() == name).then(|| value.trim())
+            })
+        };
+
+        Some(Metadata {
+            year: field("year")?.parse().ok()?,
+            day: field("day")?.parse().ok()?,
+            fetched: field("fetched")?.parse().ok()?,
+            sha256: field("sha256")?.to_string(),
+        })
+    }
+
+    pub fn to_text(&self) -> String {
+        format!(
+            "year: {}\nday: {}\nfetched: {}\nsha256: {}\n",
+            self.year, self.day, self.fetched, self.sha256
+        )
+    }
+
+    /// The day of the download, as `YYYY-MM-DD` in UTC.
+    pub fn fetched_date(&self) -> String {
+        format_date(self.fetched)
+    }
+}
+
+pub struct CachedInput {
+    pub metadata: Metadata,
+    pub input: String,
+}
+
+pub fn sha256(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            write!(hex, "{:02x}", byte).unwrap();
+            hex
+        })
+}
+
+/// Formats seconds since the Unix epoch as a UTC date.
+pub(crate) fn format_date(seconds: u64) -> String {
+    // Howard Hinnant's days-to-civil algorithm, for days since 1970-01-01.
+    let days = (seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl Default for InputCache {
+    fn default() -> Self {
+        InputCache::new(Path::new(env!("CARGO_MANIFEST_DIR")).join(CACHE_DIR))
+    }
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputCache { dir: dir.into() }
+    }
+
+    fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{:02}.txt", day))
+    }
+
+    fn metadata_path(&self, year: u16, day: u8) -> PathBuf {
+        self.input_path(year, day).with_extension("meta")
+    }
+
+    pub fn metadata(&self, year: u16, day: u8) -> Option<Metadata> {
+        let text = fs::read_to_string(self.metadata_path(year, day)).ok()?;
+        Metadata::parse(&text).filter(|metadata| metadata.year == year && metadata.day == day)
+    }
+
+    /// The cached input of `day`, if there is one that still matches its hash.
+    pub fn get(&self, year: u16, day: u8) -> Option<CachedInput> {
+        let metadata = self.metadata(year, day)?;
+        let input = fs::read_to_string(self.input_path(year, day)).ok()?;
+        (sha256(&input) == metadata.sha256).then_some(CachedInput { metadata, input })
+    }
+
+    /// Caches `input` as downloaded just now. The metadata is written last, so an interrupted
+    /// write leaves no entry rather than one with the wrong hash.
+    pub fn store(&self, year: u16, day: u8, input: &str) -> io::Result<Metadata> {
+        let fetched = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let metadata = Metadata {
+            year,
+            day,
+            fetched,
+            sha256: sha256(input),
+        };
+
+        write_atomic(&self.input_path(year, day), input)?;
+        write_atomic(&self.metadata_path(year, day), &metadata.to_text())?;
+        Ok(metadata)
+    }
+
+    /// Describes what looks wrong with `input`, the input of `day` the runner is about to
+    /// solve: it's empty, as created by scaffolding, or it isn't the one that was downloaded.
+    pub fn check_input(&self, year: u16, day: u8, input: &str) -> Option<String> {
+        if input.trim().is_empty() {
+            return Some(format!(
+                "the input of {} day {:02} is empty, fetch it with `cargo download {} {}`",
+                year, day, year, day
+            ));
+        }
+
+        let metadata = self.metadata(year, day)?;
+        (sha256(input) != metadata.sha256).then(|| {
+            format!(
+                "the input of {} day {:02} doesn't match the one downloaded on {}, \
+                 fetch it again with `cargo download {} {} --force`",
+                year,
+                day,
+                metadata.fetched_date(),
+                year,
+                day
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_669_870_800), "2022-12-01");
+        assert_eq!(format_date(951_825_600), "2000-02-29");
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = Metadata {
+            year: 2022,
+            day: 1,
+            fetched: 1_669_870_800,
+            sha256: sha256("abc"),
+        };
+        assert_eq!(
+            metadata.sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(Metadata::parse(&metadata.to_text()), Some(metadata));
+        assert_eq!(Metadata::parse("year: 2022\nday: 1\n"), None);
+    }
+
+    #[test]
+    fn test_input_cache() {
+        let dir = env::temp_dir().join(format!("aoc-input-cache-{}", std::process::id()));
+        let cache = InputCache::new(&dir);
+        assert!(cache.get(2022, 1).is_none());
+        assert!(cache.check_input(2022, 1, "1000\n").is_none());
+
+        let metadata = cache.store(2022, 1, "1000\n").unwrap();
+        assert_eq!(cache.metadata(2022, 1), Some(metadata));
+        assert_eq!(cache.get(2022, 1).unwrap().input, "1000\n");
+        assert!(cache.get(2021, 1).is_none());
+
+        assert!(cache.check_input(2022, 1, "1000\n").is_none());
+        assert!(cache
+            .check_input(2022, 1, "2000\n")
+            .unwrap()
+            .contains("`cargo download 2022 1 --force`"));
+        assert!(cache
+            .check_input(2022, 1, "\n")
+            .unwrap()
+            .contains("is empty, fetch it with `cargo download 2022 1`"));
+
+        fs::write(dir.join("2022/01.txt"), "edited").unwrap();
+        assert!(cache.get(2022, 1).is_none());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
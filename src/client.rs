@@ -0,0 +1,453 @@
+/*
+ * This file contains template code.
+ * Talks to the Advent of Code website, authenticated with the session cookie of your account.
+ */
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::puzzle::{articles, html_text};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable to send requests somewhere other than [`BASE_URL`], e.g. a local
+/// server in tests.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable holding the session cookie. Takes precedence over the session file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable pointing to the session file, if it isn't [`SESSION_FILE`] in the
+/// home directory.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+/// Name of the session file in the home directory, the one aoc-cli reads as well.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ClientError {
+    /// Neither the environment variable nor the session file holds a session cookie.
+    MissingSession { path: Option<PathBuf> },
+    /// The request didn't get a response, e.g. because the server couldn't be reached.
+    Request { url: String, message: String },
+    /// The server answered with an error status.
+    Status {
+        url: String,
+        status: u16,
+        message: String,
+    },
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession { path } => {
+                write!(f, "no session cookie, set {}", SESSION_VAR)?;
+                match path {
+                    Some(path) => write!(f, " or put it into {}", path.display()),
+                    None => write!(f, " or {}", SESSION_FILE_VAR),
+                }
+            }
+            ClientError::Request { url, message } => {
+                write!(f, "request to {} failed: {}", url, message)
+            }
+            ClientError::Status {
+                url,
+                status,
+                message,
+            } => {
+                write!(f, "{} responded with status {}", url, status)?;
+                match status {
+                    400 | 500 => write!(f, ", is the session cookie still valid?")?,
+                    404 => write!(f, ", is the puzzle unlocked yet?")?,
+                    _ => {}
+                }
+                if !message.is_empty() {
+                    write!(f, "\n{}", message)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// The session file to read, if a home directory is known.
+fn session_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(SESSION_FILE_VAR).filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE))
+}
+
+/// Strips whitespace and an optional `session=` prefix, as copied from the browser.
+fn parse_session(value: &str) -> Option<String> {
+    let value = value.trim();
+    let value = value.strip_prefix("session=").unwrap_or(value);
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Reads the session cookie from [`SESSION_VAR`], or else from the session file.
+pub fn load_session() -> Result<String, ClientError> {
+    if let Some(session) = env::var(SESSION_VAR).ok().and_then(|v| parse_session(&v)) {
+        return Ok(session);
+    }
+    let path = session_path();
+    path.as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|text| parse_session(&text))
+        .ok_or(ClientError::MissingSession { path })
+}
+
+fn base_url_from_env() -> String {
+    env::var(BASE_URL_VAR)
+        .ok()
+        .filter(|url| !url.is_empty())
+        .unwrap_or_else(|| BASE_URL.to_string())
+}
+
+pub struct Client {
+    base_url: String,
+    /// Pages like puzzle descriptions can be fetched without logging in.
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new()
+                .timeout(TIMEOUT)
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION"),
+                    " (advent-of-code-rust template)"
+                ))
+                .build(),
+        }
+    }
+
+    /// A client for the session and base URL configured in the environment.
+    pub fn from_env() -> Result<Self, ClientError> {
+        Ok(Client::new(&base_url_from_env(), Some(load_session()?)))
+    }
+
+    /// Like [`Client::from_env`], but without logging in if there is no session.
+    pub fn from_env_or_anonymous() -> Self {
+        Client::new(&base_url_from_env(), load_session().ok())
+    }
+
+    pub fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        let request = self.agent.request(method, url);
+        match &self.session {
+            Some(session) => request.set("Cookie", &format!("session={}", session)),
+            None => request,
+        }
+    }
+
+    fn read_response(
+        url: String,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, ClientError> {
+        match response {
+            Ok(response) => response.into_string().map_err(|e| ClientError::Request {
+                url,
+                message: e.to_string(),
+            }),
+            Err(ureq::Error::Status(status, response)) => Err(ClientError::Status {
+                message: response
+                    .into_string()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string(),
+                url,
+                status,
+            }),
+            Err(e) => Err(ClientError::Request {
+                url,
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    /// The HTML of the puzzle description. The second part is only included when logged in
+    /// as someone who solved the first.
+    pub fn fetch_puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let url = self.day_url(year, day);
+        let response = self.request("GET", &url).call();
+        Client::read_response(url, response)
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/input", self.day_url(year, day));
+        let response = self.request("GET", &url).call();
+        Client::read_response(url, response)
+    }
+
+    /// Submits `answer` to `part` of the puzzle and returns the verdict of the website.
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitOutcome, ClientError> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let response = self
+            .request("POST", &url)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        Client::read_response(url, response).map(|page| SubmitOutcome::parse(&page))
+    }
+}
+
+/// The verdict on a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    /// Wrong, without a hint in which direction.
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently. `wait` is how long to wait, e.g. `4m 30s`.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part was already solved, or the first one still has to be.
+    WrongLevel,
+    /// A response that none of the above matched, with the text of the page.
+    Unknown {
+        message: String,
+    },
+}
+
+impl SubmitOutcome {
+    /// Classifies the page returned for a submission by its main message.
+    pub fn parse(page: &str) -> Self {
+        let message = article_text(page);
+        if message.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if message.contains("You gave an answer too recently") {
+            let wait = message
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            SubmitOutcome::RateLimited { wait }
+        } else if message.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::WrongLevel
+        } else if message.contains("That's not the right answer") {
+            if message.contains("your answer is too high") {
+                SubmitOutcome::TooHigh
+            } else if message.contains("your answer is too low") {
+                SubmitOutcome::TooLow
+            } else {
+                SubmitOutcome::Incorrect
+            }
+        } else {
+            SubmitOutcome::Unknown { message }
+        }
+    }
+
+    /// Name of the outcome in the submission log.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SubmitOutcome::Correct => "correct",
+            SubmitOutcome::Incorrect => "incorrect",
+            SubmitOutcome::TooHigh => "too-high",
+            SubmitOutcome::TooLow => "too-low",
+            SubmitOutcome::RateLimited { .. } => "rate-limited",
+            SubmitOutcome::WrongLevel => "wrong-level",
+            SubmitOutcome::Unknown { .. } => "unknown",
+        }
+    }
+
+    /// The inverse of [`SubmitOutcome::label`]. Details that aren't logged are left empty.
+    pub fn from_label(label: &str) -> Option<Self> {
+        Some(match label {
+            "correct" => SubmitOutcome::Correct,
+            "incorrect" => SubmitOutcome::Incorrect,
+            "too-high" => SubmitOutcome::TooHigh,
+            "too-low" => SubmitOutcome::TooLow,
+            "rate-limited" => SubmitOutcome::RateLimited { wait: None },
+            "wrong-level" => SubmitOutcome::WrongLevel,
+            "unknown" => SubmitOutcome::Unknown {
+                message: String::new(),
+            },
+            _ => return None,
+        })
+    }
+
+    /// Whether the answer is known to be wrong, so it's no use submitting it again.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            SubmitOutcome::Incorrect | SubmitOutcome::TooHigh | SubmitOutcome::TooLow
+        )
+    }
+}
+
+/// The text of the first `<article>` element of `page`, or the whole page if there is none,
+/// with whitespace collapsed.
+fn article_text(page: &str) -> String {
+    let article = articles(page).first().copied().unwrap_or(page);
+    html_text(article)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Answers the next request on a local port with `status` and `body`. Returns the base
+    /// URL of the server and a handle yielding the request it received.
+    pub(crate) fn serve_once(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_parse_session() {
+        assert_eq!(parse_session(" abc123\n"), Some("abc123".to_string()));
+        assert_eq!(parse_session("session=abc123"), Some("abc123".to_string()));
+        assert_eq!(parse_session("\n"), None);
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+        let client = Client::new(&format!("{}/", base_url), Some("abc123".to_string()));
+        assert_eq!(client.fetch_input(2022, 1), Ok("1000\n2000\n".to_string()));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request
+            .lines()
+            .any(|line| line.eq_ignore_ascii_case("cookie: session=abc123")));
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let page = "<main><article><p>That's the right answer!  You are \
+                    <span class=\"day-success\">one gold star</span> closer.</p></article></main>";
+        let (base_url, server) = serve_once("200 OK", page);
+        let client = Client::new(&base_url, Some("abc123".to_string()));
+        assert_eq!(
+            client.submit_answer(2022, 1, 2, "45000"),
+            Ok(SubmitOutcome::Correct)
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=45000"));
+    }
+
+    #[test]
+    fn test_submit_outcome() {
+        let outcome = |message: &str| {
+            SubmitOutcome::parse(&format!(
+                "<html><article><p>{} <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>",
+                message
+            ))
+        };
+
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too high.  If you're stuck, ..."),
+            SubmitOutcome::TooHigh
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too low."),
+            SubmitOutcome::TooLow
+        );
+        assert_eq!(
+            outcome("That's not the right answer.  If you're stuck, ..."),
+            SubmitOutcome::Incorrect
+        );
+        assert_eq!(
+            outcome(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 4m 30s left to wait."
+            ),
+            SubmitOutcome::RateLimited {
+                wait: Some("4m 30s".to_string())
+            }
+        );
+        assert_eq!(
+            outcome("You don't seem to be solving the right level.  Did you already complete it?"),
+            SubmitOutcome::WrongLevel
+        );
+        assert_eq!(
+            SubmitOutcome::parse("Whoops, <b>maintenance</b>\n"),
+            SubmitOutcome::Unknown {
+                message: "Whoops, maintenance".to_string()
+            }
+        );
+        assert_eq!(
+            SubmitOutcome::from_label(SubmitOutcome::TooLow.label()),
+            Some(SubmitOutcome::TooLow)
+        );
+    }
+
+    #[test]
+    fn test_fetch_input_status() {
+        let (base_url, server) = serve_once("404 Not Found", "Not found\n");
+        let client = Client::new(&base_url, Some("abc123".to_string()));
+        assert_eq!(
+            client.fetch_input(2022, 25),
+            Err(ClientError::Status {
+                url: format!("{}/2022/day/25/input", base_url),
+                status: 404,
+                message: "Not found".to_string(),
+            })
+        );
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,42 @@
+/*
+ * Registry of every solved day, used by the runner to call solutions in-process.
+ * Each year has a module with its days, listed in `YEARS`.
+ */
+use crate::Solution;
+
+pub mod y2022;
+
+/// The year of the first Advent of Code.
+pub const FIRST_YEAR: u16 = 2015;
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static dyn Solution,
+    /// Takes long enough to leave out with `cargo all --skip-slow`.
+    pub slow: bool,
+}
+
+/// The days of each year, ordered by year.
+pub static YEARS: &[&[Day]] = &[y2022::DAYS];
+
+/// All registered days, ordered by year and day.
+pub fn all() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+pub fn get(year: u16, day: u8) -> Option<&'static Day> {
+    all().find(|d| d.year == year && d.day == day)
+}
+
+/// The years with at least one registered day, in order.
+pub fn years() -> Vec<u16> {
+    let mut years = all().map(|day| day.year).collect::<Vec<_>>();
+    years.dedup();
+    years
+}
+
+/// The year commands default to when none is given.
+pub fn latest_year() -> u16 {
+    years().last().copied().unwrap_or(FIRST_YEAR)
+}
@@ -0,0 +1,36 @@
+use crate::helpers::parse::{blocks, integer, lines};
+use crate::{run_parser, Error};
+
+/// The calories carried by each elf.
+fn parse(input: &str) -> Result<Vec<u32>, Error> {
+    let elves = run_parser(blocks(lines(integer::<u32>)), input)?;
+    Ok(elves.iter().map(|calories| calories.iter().sum()).collect())
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    Ok(parse(input)?.into_iter().max())
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    let mut top3 = [0_u32; 3];
+    for calories in parse(input)? {
+        update_top3(calories, &mut top3);
+    }
+    Ok(Some(top3.iter().sum()))
+}
+
+// I know there's a better way to do this...
+fn update_top3(current_calories: u32, top3: &mut [u32; 3]) {
+    if current_calories > top3[0] {
+        top3[2] = top3[1];
+        top3[1] = top3[0];
+        top3[0] = current_calories;
+    } else if current_calories > top3[1] {
+        top3[2] = top3[1];
+        top3[1] = current_calories;
+    } else if current_calories > top3[2] {
+        top3[2] = current_calories;
+    }
+}
+
+crate::solution!(parse);
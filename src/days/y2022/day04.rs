@@ -0,0 +1,74 @@
+use nom::{
+    bytes::complete::tag,
+    combinator::{map, verify},
+    error::context,
+    sequence::separated_pair,
+};
+
+use crate::helpers::intervals::Interval;
+use crate::helpers::parse::{integer, lines};
+use crate::{run_parser, Error, ParseResult};
+
+/// The sections assigned to a pair of elves.
+type Pair = (Interval<i32>, Interval<i32>);
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    Ok(Some(
+        parse(input)?
+            .iter()
+            .filter(|(left, right)| right.contains_interval(left) || left.contains_interval(right))
+            .count() as u32,
+    ))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    Ok(Some(
+        parse(input)?
+            .iter()
+            .filter(|(left, right)| left.overlaps(right))
+            .count() as u32,
+    ))
+}
+
+fn parse(input: &str) -> Result<Vec<Pair>, Error> {
+    run_parser(
+        lines(separated_pair(
+            parse_range,
+            context("','", tag(",")),
+            parse_range,
+        )),
+        input,
+    )
+}
+
+fn parse_range(input: &str) -> ParseResult<'_, Interval<i32>> {
+    map(
+        context(
+            "a range that doesn't end before it starts",
+            verify(
+                separated_pair(integer, context("'-'", tag("-")), integer),
+                |(start, end)| start <= end,
+            ),
+        ),
+        |(start, end)| Interval::new(start, end),
+    )(input)
+}
+
+crate::solution!(parse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            part_one("2-4,6-8\n2-3;4-5").unwrap_err().to_string(),
+            "parse error at line 2, col 4: expected ','"
+        );
+        assert_eq!(
+            part_one("2-4,8-6").unwrap_err().to_string(),
+            "parse error at line 1, col 5: expected a range that doesn't end before it starts"
+        );
+    }
+}
@@ -4,10 +4,12 @@ use nom::{
     bytes::complete::tag,
     character::complete::{self, alpha1, digit1, multispace1, newline, space1},
     multi::{many1, separated_list1},
-    sequence::{delimited, preceded},
-    IResult,
+    sequence::{delimited, pair, preceded},
 };
 
+use crate::helpers::parse::lines;
+use crate::{run_parser, Error, ParseResult};
+
 #[derive(Debug)]
 struct Move {
     quantity: u8,
@@ -15,11 +17,11 @@ struct Move {
     to: usize,
 }
 
-fn parse_crate(input: &str) -> IResult<&str, &str> {
+fn parse_crate(input: &str) -> ParseResult<'_, &str> {
     delimited(complete::char('['), alpha1, complete::char(']'))(input)
 }
 
-fn parse_crate_position(input: &str) -> IResult<&str, Option<&str>> {
+fn parse_crate_position(input: &str) -> ParseResult<'_, Option<&str>> {
     let (input, c) = alt((tag("   "), parse_crate))(input)?;
 
     let result = match c {
@@ -29,11 +31,11 @@ fn parse_crate_position(input: &str) -> IResult<&str, Option<&str>> {
     Ok((input, result))
 }
 
-fn parse_crate_row(input: &str) -> IResult<&str, Vec<Option<&str>>> {
+fn parse_crate_row(input: &str) -> ParseResult<'_, Vec<Option<&str>>> {
     separated_list1(complete::char(' '), parse_crate_position)(input)
 }
 
-fn parse_crates(input: &str) -> IResult<&str, Vec<Vec<&str>>> {
+fn parse_crates(input: &str) -> ParseResult<'_, Vec<Vec<&str>>> {
     let (input, crates_h) = separated_list1(newline, parse_crate_row)(input)?;
     let (input, _) = newline(input)?;
     let (input, _) = many1(preceded(space1, digit1))(input)?;
@@ -51,7 +53,7 @@ fn parse_crates(input: &str) -> IResult<&str, Vec<Vec<&str>>> {
     Ok((input, crates))
 }
 
-fn parse_move(input: &str) -> IResult<&str, Move> {
+fn parse_move(input: &str) -> ParseResult<'_, Move> {
     let (input, _) = tag("move ")(input)?;
     let (input, quantity) = complete::u8(input)?;
     let (input, _) = tag(" from ")(input)?;
@@ -68,13 +70,16 @@ fn parse_move(input: &str) -> IResult<&str, Move> {
     ))
 }
 
-fn parse_moves(input: &str) -> IResult<&str, Vec<Move>> {
-    separated_list1(newline, parse_move)(input)
+fn parse_moves(input: &str) -> ParseResult<'_, Vec<Move>> {
+    lines(parse_move)(input)
+}
+
+fn parse(input: &str) -> Result<(Vec<Vec<&str>>, Vec<Move>), Error> {
+    run_parser(pair(parse_crates, parse_moves), input)
 }
 
-pub fn part_one(input: &str) -> Option<String> {
-    let (input, mut crates) = parse_crates(input).unwrap();
-    let (_, moves) = parse_moves(input).unwrap();
+pub fn part_one(input: &str) -> Result<Option<String>, Error> {
+    let (mut crates, moves) = parse(input)?;
 
     for m in moves.iter() {
         let end = crates[m.from].len();
@@ -85,12 +90,11 @@ pub fn part_one(input: &str) -> Option<String> {
     }
 
     let result: String = crates.iter().filter_map(|c| c.last()).join("");
-    Some(result)
+    Ok(Some(result))
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let (input, mut crates) = parse_crates(input).unwrap();
-    let (_, moves) = parse_moves(input).unwrap();
+pub fn part_two(input: &str) -> Result<Option<String>, Error> {
+    let (mut crates, moves) = parse(input)?;
 
     for m in moves.iter() {
         let end = crates[m.from].len();
@@ -101,36 +105,7 @@ pub fn part_two(input: &str) -> Option<String> {
     }
 
     let result: String = crates.iter().filter_map(|c| c.last()).join("");
-    Some(result)
+    Ok(Some(result))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some("CMZ".to_string()));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some("MCD".to_string()));
-    }
-
-    #[test]
-    #[ignore]
-    fn test_solutions() {
-        let input = advent_of_code::read_file("inputs", 5);
-        assert_eq!(part_one(&input), Some("LJSVLTWQM".to_string()));
-        assert_eq!(part_two(&input), Some("BRQWDBBJM".to_string()));
-    }
-}
+crate::solution!(parse);
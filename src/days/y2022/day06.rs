@@ -0,0 +1,29 @@
+use std::collections::BTreeSet;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let chars = input.chars().collect::<Vec<char>>();
+    let signal = chars
+        .windows(4)
+        .enumerate()
+        .find(|(_, slice)| {
+            let set = slice.iter().collect::<BTreeSet<&char>>();
+            slice.len() == set.len()
+        })
+        .unwrap();
+    Some(signal.0 as u32 + 4)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let chars = input.chars().collect::<Vec<char>>();
+    let signal = chars
+        .windows(14)
+        .enumerate()
+        .find(|(_, slice)| {
+            let set = slice.iter().collect::<BTreeSet<&char>>();
+            slice.len() == set.len()
+        })
+        .unwrap();
+    Some(signal.0 as u32 + 14)
+}
+
+crate::solution!();
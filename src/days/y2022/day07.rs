@@ -5,15 +5,27 @@ use nom::{
     branch::alt,
     bytes::complete::{tag, take_till},
     character::complete::{digit1, newline, space1},
-    multi::{separated_list0, separated_list1},
-    sequence::separated_pair,
-    IResult,
+    combinator::{cut, peek},
+    error::context,
+    multi::separated_list0,
+    sequence::preceded,
 };
 
+use crate::helpers::parse::{integer, lines};
+use crate::{run_parser, Error, ParseResult};
+
+crate::params! {
+    /// Directories up to this size count towards the answer of part one.
+    max_dir_size: u32 = 100_000,
+    disk_size: u32 = 70_000_000,
+    /// Free space the update needs in part two.
+    required_space: u32 = 30_000_000,
+}
+
 #[derive(Debug)]
 enum Contents<'n> {
     File { size: u32 },
-    Dir(&'n str),
+    Dir(#[allow(dead_code)] &'n str),
 }
 
 #[derive(Debug)]
@@ -29,11 +41,11 @@ enum CD<'n> {
     Name(&'n str),
 }
 
-fn take_till_newline(input: &str) -> IResult<&str, &str> {
+fn take_till_newline(input: &str) -> ParseResult<'_, &str> {
     take_till(|c| c == '\n')(input)
 }
 
-fn cd(input: &str) -> IResult<&str, Command> {
+fn cd(input: &str) -> ParseResult<'_, Command<'_>> {
     let (input, _) = tag("$ cd ")(input)?;
     let (input, name) = take_till_newline(input)?;
     Ok((
@@ -46,32 +58,28 @@ fn cd(input: &str) -> IResult<&str, Command> {
     ))
 }
 
-fn ls(input: &str) -> IResult<&str, Command> {
+fn ls(input: &str) -> ParseResult<'_, Command<'_>> {
     let (input, _) = tag("$ ls")(input)?;
     let (input, _) = newline(input)?;
     let (input, contents) = separated_list0(newline, alt((file, dir)))(input)?;
     Ok((input, Command::List(contents)))
 }
 
-fn file(input: &str) -> IResult<&str, Contents> {
-    let (input, (size, _)) = separated_pair(digit1, space1, take_till_newline)(input)?;
-
-    Ok((
-        input,
-        Contents::File {
-            size: size.parse().unwrap(),
-        },
-    ))
+fn file(input: &str) -> ParseResult<'_, Contents<'_>> {
+    // once a line starts with a size, errors are reported instead of ending the listing.
+    let (input, size) = preceded(peek(digit1), cut(integer))(input)?;
+    let (input, _) = preceded(space1, take_till_newline)(input)?;
+    Ok((input, Contents::File { size }))
 }
 
-fn dir(input: &str) -> IResult<&str, Contents> {
+fn dir(input: &str) -> ParseResult<'_, Contents<'_>> {
     let (input, _) = tag("dir ")(input)?;
     let (input, name) = take_till_newline(input)?;
     Ok((input, Contents::Dir(name)))
 }
 
-fn commands(input: &str) -> IResult<&str, Vec<Command>> {
-    separated_list1(newline, alt((ls, cd)))(input)
+fn commands(input: &str) -> ParseResult<'_, Vec<Command<'_>>> {
+    lines(context("'$ ls' or '$ cd'", alt((ls, cd))))(input)
 }
 
 fn calc_sizes<'n>(
@@ -111,30 +119,34 @@ fn calc_sizes<'n>(
     (context, sizes)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let cmds = commands(input).unwrap().1;
+fn parse(input: &str) -> Result<Vec<Command<'_>>, Error> {
+    run_parser(commands, input)
+}
+
+pub fn part_one(input: &str, params: &Params) -> Result<Option<u32>, Error> {
+    let cmds = parse(input)?;
 
     let (_, sizes) = cmds.iter().fold((vec![], BTreeMap::new()), calc_sizes);
-    Some(
+    Ok(Some(
         sizes
-            .iter()
-            .filter_map(|(_, size)| if *size < 100000 { Some(size) } else { None })
+            .values()
+            .filter(|size| **size < params.max_dir_size)
             .sum::<u32>(),
-    )
+    ))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let total_size = 70_000_000;
-    let required_size = 30_000_000;
-    let cmds = commands(input).unwrap().1;
+pub fn part_two(input: &str, params: &Params) -> Result<Option<u32>, Error> {
+    let total_size = params.disk_size;
+    let required_size = params.required_space;
+    let cmds = parse(input)?;
     let (_, sizes) = cmds.iter().fold((vec![], BTreeMap::new()), calc_sizes);
     let total_used_size = sizes.values().next().unwrap();
     let total_available = total_size - total_used_size;
     let min_needed = required_size - total_available;
 
-    sizes
-        .iter()
-        .filter_map(|(_, size)| {
+    Ok(sizes
+        .values()
+        .filter_map(|size| {
             if *size >= min_needed {
                 Some(*size)
             } else {
@@ -142,36 +154,7 @@ pub fn part_two(input: &str) -> Option<u32> {
             }
         })
         .sorted()
-        .find_or_last(|size| size >= &min_needed)
+        .find_or_last(|size| size >= &min_needed))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), Some(95437));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), Some(24933642));
-    }
-
-    #[test]
-    #[ignore]
-    fn test_solutions() {
-        let input = advent_of_code::read_file("inputs", 7);
-        assert_eq!(part_one(&input), Some(1517599));
-        assert_eq!(part_two(&input), Some(2481982));
-    }
-}
+crate::solution!(parse, Params);
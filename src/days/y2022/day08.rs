@@ -0,0 +1,65 @@
+use itertools::FoldWhile::{Continue, Done};
+use itertools::Itertools;
+
+use crate::helpers::grid::{Direction, Grid, Pos};
+use crate::Error;
+
+fn parse_heights(input: &str) -> Result<Grid<u8>, Error> {
+    Grid::parse(input, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+}
+
+fn check_blockage(
+    visible_tree: u8,
+    target_tree: u8,
+    current_score: u32,
+) -> itertools::FoldWhile<u32> {
+    if visible_tree >= target_tree {
+        Done(current_score + 1)
+    } else {
+        Continue(current_score + 1)
+    }
+}
+
+/// Whether the tree at `pos` can be seen from outside the grid. Edge trees always can,
+/// since nothing is in their way.
+fn is_visible(heights: &Grid<u8>, pos: Pos) -> bool {
+    let height = heights[pos];
+    Direction::ORTHOGONAL.into_iter().any(|direction| {
+        heights
+            .ray(pos, direction)
+            .all(|(_, &other)| other < height)
+    })
+}
+
+fn scenic_score(heights: &Grid<u8>, pos: Pos) -> u32 {
+    let tree = heights[pos];
+    Direction::ORTHOGONAL
+        .into_iter()
+        .map(|direction| {
+            heights
+                .ray(pos, direction)
+                .fold_while(0, |acc, (_, &t)| check_blockage(t, tree, acc))
+                .into_inner()
+        })
+        .product()
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    let heights = parse_heights(input)?;
+    Ok(Some(
+        heights
+            .positions()
+            .filter(|&pos| is_visible(&heights, pos))
+            .count() as u32,
+    ))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    let heights = parse_heights(input)?;
+    Ok(heights
+        .positions()
+        .map(|pos| scenic_score(&heights, pos))
+        .max())
+}
+
+crate::solution!(parse_heights);
@@ -0,0 +1,143 @@
+use nom::{
+    character::complete::{one_of, space1, u16},
+    error::context,
+    sequence::separated_pair,
+};
+
+use crate::helpers::geom::{Direction, Point2};
+use crate::helpers::parse::lines;
+use crate::helpers::SparseGrid;
+use crate::{run_parser, Error, ParseResult};
+
+#[derive(Copy, Clone, Debug)]
+enum Move {
+    Up(u16),
+    Down(u16),
+    Left(u16),
+    Right(u16),
+}
+
+impl Move {
+    fn amount(self: Move) -> u16 {
+        match self {
+            Move::Up(amount) => amount,
+            Move::Down(amount) => amount,
+            Move::Left(amount) => amount,
+            Move::Right(amount) => amount,
+        }
+    }
+
+    fn direction(self: Move) -> Direction {
+        match self {
+            Move::Up(_) => Direction::Up,
+            Move::Down(_) => Direction::Down,
+            Move::Left(_) => Direction::Left,
+            Move::Right(_) => Direction::Right,
+        }
+    }
+}
+
+type Point = Point2<i64>;
+
+fn is_adjacent(a: Point, b: Point) -> bool {
+    a.chebyshev(b) <= 1
+}
+
+fn parse_move(input: &str) -> ParseResult<'_, Move> {
+    let (input, (m, amount)) =
+        separated_pair(context("U, D, L or R", one_of("UDLR")), space1, u16)(input)?;
+    let m = match m {
+        'U' => Move::Up(amount),
+        'D' => Move::Down(amount),
+        'L' => Move::Left(amount),
+        _ => Move::Right(amount),
+    };
+    Ok((input, m))
+}
+
+fn parse(input: &str) -> Result<Vec<Move>, Error> {
+    run_parser(lines(parse_move), input)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    let moves = parse(input)?;
+
+    let mut visited = SparseGrid::<()>::new();
+    let mut head = Point::default();
+    let mut tail = Point::default();
+
+    visited.insert(tail, ());
+
+    for m in moves {
+        for _ in 0..m.amount() {
+            let prev_head = head;
+            head = head.step(m.direction());
+
+            if !is_adjacent(tail, head) {
+                tail = prev_head;
+                visited.insert(tail, ());
+            }
+        }
+    }
+    Ok(Some(visited.len() as u32))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    let moves = parse(input)?;
+
+    let mut visited = SparseGrid::<()>::new();
+    let mut body = vec![Point::default(); 10];
+    const TAIL_INDEX: usize = 9;
+
+    visited.insert(body[TAIL_INDEX], ());
+
+    for m in moves {
+        for _ in 0..m.amount() {
+            let prev_body = body.clone();
+
+            for (i, segment) in prev_body.iter().enumerate() {
+                match i {
+                    0 => body[i] = body[i].step(m.direction()),
+                    1..=TAIL_INDEX => {
+                        let preceeding_segment = body[i - 1];
+                        if is_adjacent(*segment, preceeding_segment) {
+                            break;
+                        }
+
+                        body[i] += (preceeding_segment - *segment).signum();
+                    }
+                    _ => panic!("Unexpected snake length"),
+                }
+            }
+
+            visited.insert(body[TAIL_INDEX], ());
+        }
+    }
+    Ok(Some(visited.len() as u32))
+}
+
+crate::solution!(parse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_adjacent() {
+        let p1 = Point::default();
+
+        for x in -1..=1 {
+            for y in -1..=1 {
+                assert!(is_adjacent(p1, Point2::new(x, y)));
+            }
+
+            assert!(!is_adjacent(p1, Point2::new(x, -2)));
+            assert!(!is_adjacent(p1, Point2::new(x, 2)));
+        }
+
+        for y in -1..=1 {
+            assert!(!is_adjacent(p1, Point2::new(-2, y)));
+            assert!(!is_adjacent(p1, Point2::new(2, y)));
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{i32, space1},
+    combinator::map,
+    error::context,
+    sequence::preceded,
+};
+
+use crate::helpers::parse::lines;
+use crate::{run_parser, Error, ParseResult};
+
+#[derive(Debug)]
+enum Operation {
+    Add(i32),
+    Noop,
+}
+
+fn parse_operation(input: &str) -> ParseResult<'_, Operation> {
+    context(
+        "addx or noop",
+        alt((
+            map(preceded(tag("addx"), preceded(space1, i32)), Operation::Add),
+            map(tag("noop"), |_| Operation::Noop),
+        )),
+    )(input)
+}
+
+fn run(operations: Vec<Operation>) -> Vec<i32> {
+    let mut x = 1;
+    let mut x_history = vec![x];
+    operations.into_iter().for_each(|op| match op {
+        Operation::Add(value) => {
+            x_history.push(x);
+            x_history.push(x);
+            x += value;
+        }
+        Operation::Noop => {
+            x_history.push(x);
+        }
+    });
+    x_history
+}
+
+fn parse(input: &str) -> Result<Vec<Operation>, Error> {
+    run_parser(lines(parse_operation), input)
+}
+
+pub fn part_one(input: &str) -> Result<Option<i32>, Error> {
+    let operations = parse(input)?;
+    let x_history = run(operations);
+
+    let interesting_cycles = [20_usize, 60, 100, 140, 180, 220];
+
+    let sum: i32 = interesting_cycles
+        .iter()
+        .filter(|&&c| c < x_history.len()) // this is to support the test that only checks against the first 20 cycles
+        .map(|&c| -> i32 {
+            let x = x_history[c];
+            x * c as i32
+        })
+        .sum();
+
+    Ok(Some(sum))
+}
+
+pub fn part_two(input: &str) -> Result<Option<String>, Error> {
+    let operations = parse(input)?;
+    let x_history = run(operations);
+
+    let mut crt = vec!["."; 240];
+    x_history
+        .iter()
+        .dropping(1)
+        .enumerate()
+        .for_each(|(pixel, &x)| {
+            // crt is a 1d vec, storing all 6 rows of the display contiguously. Each row is 40 pixels.
+            // So h_pos is what would be the x component of a pixel's coordinate if we were using cartesian coordinates.
+            let h_pos = (pixel % 40) as i32;
+            if h_pos.abs_diff(x) <= 1 {
+                crt[pixel] = "#";
+            }
+        });
+
+    crt.insert(40, "\n");
+    crt.insert(81, "\n");
+    crt.insert(122, "\n");
+    crt.insert(163, "\n");
+    crt.insert(204, "\n");
+
+    let result = crt.concat();
+    Ok(Some(result))
+}
+
+crate::solution!(parse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            part_one("subx 5").unwrap_err().to_string(),
+            "parse error at line 1, col 1: expected addx or noop"
+        );
+        // the error is about the first operation that doesn't parse.
+        assert_eq!(
+            part_one("noop\naddx 3\nsubx 5").unwrap_err().to_string(),
+            "parse error at line 3, col 1: expected addx or noop"
+        );
+    }
+}
@@ -4,13 +4,22 @@ use itertools::Itertools;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{digit1, line_ending, multispace1, newline, space1},
-    combinator::opt,
+    character::complete::{digit1, multispace1, one_of, space1},
+    combinator::{cut, map},
+    error::context,
     multi::separated_list1,
     sequence::separated_pair,
-    IResult,
 };
 
+use crate::helpers::parse::{blocks, integer};
+use crate::{run_parser, Error, ParseResult};
+
+crate::params! {
+    rounds: u32 = 20,
+    /// Part two has no relief after inspections, and runs for much longer.
+    rounds_part_two: u32 = 10_000,
+}
+
 #[derive(Debug, Clone)]
 struct Monkey {
     items: VecDeque<u64>,
@@ -28,6 +37,7 @@ impl Monkey {
             Operation::Multiply(value) => item * value,
             Operation::Add(value) => item + value,
             Operation::Square => item * item,
+            Operation::Double => item + item,
         };
 
         if reduce_worry {
@@ -53,53 +63,58 @@ enum Operation {
     Multiply(u64),
     Add(u64),
     Square,
+    Double,
 }
 
-fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
-    let (input, _) = separated_pair(tag("Monkey"), space1, digit1)(input)?;
+fn parse_monkey(input: &str) -> ParseResult<'_, Monkey> {
+    let (input, _) = separated_pair(context("'Monkey'", tag("Monkey")), space1, digit1)(input)?;
+    // once the header matched, errors are reported instead of ending the list of monkeys.
+    cut(parse_monkey_body)(input)
+}
+
+fn parse_monkey_body(input: &str) -> ParseResult<'_, Monkey> {
     let (input, _) = tag(":")(input)?;
     let (input, _) = multispace1(input)?;
 
     // Items
     let (input, (_, items)) = separated_pair(
-        tag("Starting items:"),
+        context("'Starting items:'", tag("Starting items:")),
         space1,
         separated_list1(tag(", "), nom::character::complete::u64),
     )(input)?;
 
     // Operation
     let (input, _) = multispace1(input)?;
-    let (input, _) = tag("Operation: new = old ")(input)?;
-    let (input, op) = nom::combinator::map(
-        separated_pair(alt((tag("*"), tag("+"))), space1, alt((tag("old"), digit1))),
-        |(op_str, value)| match op_str {
-            "*" => {
-                if let Ok(value) = value.parse() {
-                    Operation::Multiply(value)
-                } else {
-                    Operation::Square
-                }
-            }
-            "+" => Operation::Add(value.parse().unwrap()),
-            _ => panic!("Malformed operation"),
-        },
+    let (input, _) = context("'Operation: new = old'", tag("Operation: new = old "))(input)?;
+    let (input, (operator, value)) = separated_pair(
+        context("'*' or '+'", one_of("*+")),
+        space1,
+        alt((map(tag("old"), |_| None), map(integer, Some))),
     )(input)?;
+    let op = match (operator, value) {
+        ('*', Some(value)) => Operation::Multiply(value),
+        ('*', None) => Operation::Square,
+        (_, Some(value)) => Operation::Add(value),
+        (_, None) => Operation::Double,
+    };
 
     // Test
     let (input, _) = multispace1(input)?;
-    let (input, _) = tag("Test: divisible by ")(input)?;
+    let (input, _) = context("'Test: divisible by'", tag("Test: divisible by "))(input)?;
     let (input, divisor) = nom::character::complete::u64(input)?;
     let (input, _) = multispace1(input)?;
-    let (input, _) = tag("If true: throw to monkey ")(input)?;
-    let (input, true_monkey) =
-        nom::combinator::map(nom::character::complete::u64, |i| i as usize)(input)?;
+    let (input, _) = context(
+        "'If true: throw to monkey'",
+        tag("If true: throw to monkey "),
+    )(input)?;
+    let (input, true_monkey) = map(nom::character::complete::u64, |i| i as usize)(input)?;
     let (input, _) = multispace1(input)?;
-    let (input, _) = tag("If false: throw to monkey ")(input)?;
-    let (input, false_monkey) =
-        nom::combinator::map(nom::character::complete::u64, |i| i as usize)(input)?;
+    let (input, _) = context(
+        "'If false: throw to monkey'",
+        tag("If false: throw to monkey "),
+    )(input)?;
+    let (input, false_monkey) = map(nom::character::complete::u64, |i| i as usize)(input)?;
 
-    // Eat the line_ending if it's there
-    let (input, _) = opt(line_ending)(input)?;
     Ok((
         input,
         Monkey {
@@ -113,10 +128,14 @@ fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
     ))
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (_, mut monkeys) = separated_list1(newline, parse_monkey)(input).unwrap();
+fn parse(input: &str) -> Result<Vec<Monkey>, Error> {
+    run_parser(blocks(parse_monkey), input)
+}
+
+pub fn part_one(input: &str, params: &Params) -> Result<Option<u64>, Error> {
+    let mut monkeys = parse(input)?;
     let lcm: u64 = monkeys.iter().map(|m| m.divisible_by).product();
-    for _ in 0..20 {
+    for _ in 0..params.rounds {
         for i in 0..monkeys.len() {
             for _ in 0..monkeys[i].items.len() {
                 let monkey = monkeys.get_mut(i).unwrap();
@@ -125,7 +144,7 @@ pub fn part_one(input: &str) -> Option<u64> {
             }
         }
     }
-    Some(
+    Ok(Some(
         monkeys
             .iter()
             .map(|m| m.inspections)
@@ -134,13 +153,13 @@ pub fn part_one(input: &str) -> Option<u64> {
             .collect_vec()[..=1]
             .iter()
             .product(),
-    )
+    ))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (_, mut monkeys) = separated_list1(newline, parse_monkey)(input).unwrap();
+pub fn part_two(input: &str, params: &Params) -> Result<Option<u64>, Error> {
+    let mut monkeys = parse(input)?;
     let lcm: u64 = monkeys.iter().map(|m| m.divisible_by).product();
-    for _ in 0..10000 {
+    for _ in 0..params.rounds_part_two {
         for i in 0..monkeys.len() {
             for _ in 0..monkeys[i].items.len() {
                 let monkey = monkeys.get_mut(i).unwrap();
@@ -149,7 +168,7 @@ pub fn part_two(input: &str) -> Option<u64> {
             }
         }
     }
-    Some(
+    Ok(Some(
         monkeys
             .iter()
             .map(|m| m.inspections)
@@ -158,37 +177,47 @@ pub fn part_two(input: &str) -> Option<u64> {
             .collect_vec()[..=1]
             .iter()
             .product(),
-    )
+    ))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+crate::solution!(parse, Params);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_one(&input), Some(10605));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_two(&input), Some(2713310158));
-    }
-
-    #[test]
-    #[ignore]
-    fn test_solutions() {
-        let input = advent_of_code::read_file("inputs", 11);
-        assert_eq!(part_one(&input), Some(56350));
-        assert_eq!(part_two(&input), Some(13954061248));
+    fn test_parse_error() {
+        let input = crate::read_example(2022, 11, "example").input;
+        assert_eq!(
+            part_one(
+                &input.replacen("Test: divisible by", "Test:", 1),
+                &Params::default()
+            )
+            .unwrap_err()
+            .to_string(),
+            "parse error at line 4, col 3: expected 'Test: divisible by'"
+        );
+        assert_eq!(
+            part_one(
+                &input.replace("If false: throw to monkey 0", "If: throw to monkey 0"),
+                &Params::default()
+            )
+            .unwrap_err()
+            .to_string(),
+            "parse error at line 13, col 5: expected 'If false: throw to monkey'"
+        );
+        assert_eq!(
+            part_one(
+                &input.replacen("old * 19", "old - 19", 1),
+                &Params::default()
+            )
+            .unwrap_err()
+            .to_string(),
+            "parse error at line 3, col 24: expected '*' or '+'"
+        );
+        let monkeys = parse(&input.replacen("old * 19", "old + old", 1)).unwrap();
+        assert!(matches!(monkeys[0].operation, Operation::Double));
     }
 
     #[test]
@@ -204,11 +233,11 @@ mod tests {
 
         assert_eq!(m1.inspect(true, 3000), (2, 3));
         assert_eq!(m1.inspections, 1);
-        assert_eq!(m1.items.is_empty(), true);
+        assert!(m1.items.is_empty());
 
         m1.items.push_front(7);
         assert_eq!(m1.inspect(true, 3000), (1, 4));
         assert_eq!(m1.inspections, 2);
-        assert_eq!(m1.items.is_empty(), true);
+        assert!(m1.items.is_empty());
     }
 }
@@ -0,0 +1,73 @@
+use itertools::Itertools;
+
+use crate::helpers::grid::{Grid, Pos};
+use crate::Error;
+use petgraph::{algo::dijkstra, prelude::DiGraphMap};
+
+/// A position with its height.
+type Node = (Pos, char);
+type Edge = (Node, Node);
+
+fn parse_graph(input: &str) -> Result<(Vec<Edge>, Pos, Pos), Error> {
+    let grid = Grid::parse(input, "a letter", |c| c.is_ascii_alphabetic().then_some(c))?;
+
+    let start = grid
+        .position(|&c| c == 'S')
+        .ok_or_else(|| Error::parse(input, "", "a start position 'S'"))?;
+    let end = grid
+        .position(|&c| c == 'E')
+        .ok_or_else(|| Error::parse(input, "", "a best signal position 'E'"))?;
+
+    let grid = grid.map(|&c| match c {
+        'S' => 'a',
+        'E' => 'z',
+        other => other,
+    });
+
+    let edges = grid
+        .positions()
+        .flat_map(|c| {
+            let c_height = grid[c];
+            grid.neighbors(c)
+                .filter_map(|n| {
+                    let neighbor_height = grid[n];
+                    if c_height as u8 + 1 >= neighbor_height as u8 {
+                        Some(((c, c_height), (n, neighbor_height)))
+                    } else {
+                        None
+                    }
+                })
+                .collect_vec()
+        })
+        .collect::<Vec<Edge>>();
+
+    Ok((edges, start, end))
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    let (edges, start, end) = parse_graph(input)?;
+    let graph = DiGraphMap::<Node, ()>::from_edges(&edges);
+    let result = dijkstra(&graph, (start, 'a'), Some((end, 'z')), |_| 1);
+    Ok(Some(result[&(end, 'z')]))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    let (edges, _, end) = parse_graph(input)?;
+    let graph = DiGraphMap::<Node, ()>::from_edges(edges.iter().map(|(a, b)| (*b, *a)));
+
+    Ok(dijkstra(&graph, (end, 'z'), None, |_| 1)
+        .iter()
+        .filter_map(
+            |(node, cost)| {
+                if node.1 == 'a' {
+                    Some(*cost)
+                } else {
+                    None
+                }
+            },
+        )
+        .sorted()
+        .next())
+}
+
+crate::solution!(parse_graph);
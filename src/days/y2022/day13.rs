@@ -6,11 +6,14 @@ use nom::{
     bytes::complete::tag,
     character::complete::newline,
     combinator::map,
-    multi::{separated_list0, separated_list1},
+    multi::separated_list0,
     sequence::{delimited, separated_pair},
-    IResult, Parser,
+    Parser,
 };
 
+use crate::helpers::parse::blocks;
+use crate::{run_parser, Error, ParseResult};
+
 #[derive(Debug)]
 struct Pair {
     left: Packet,
@@ -61,21 +64,25 @@ impl PartialOrd for Packet {
     }
 }
 
-fn packet(input: &str) -> IResult<&str, Packet> {
+fn packet(input: &str) -> ParseResult<'_, Packet> {
     alt((
         delimited(tag("["), separated_list0(tag(","), packet), tag("]")).map(Packet::List),
         nom::character::complete::u32.map(Packet::Number),
     ))(input)
 }
 
-fn pair(input: &str) -> IResult<&str, Pair> {
+fn pair(input: &str) -> ParseResult<'_, Pair> {
     map(separated_pair(packet, newline, packet), |(left, right)| {
         Pair { left, right }
     })(input)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (_, pairs) = separated_list1(tag("\n\n"), pair)(input).unwrap();
+fn parse(input: &str) -> Result<Vec<Pair>, Error> {
+    run_parser(blocks(pair), input)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    let pairs = parse(input)?;
     let result = pairs
         .iter()
         .enumerate()
@@ -87,11 +94,11 @@ pub fn part_one(input: &str) -> Option<u32> {
             }
         })
         .sum();
-    Some(result)
+    Ok(Some(result))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (_, pairs) = separated_list1(tag("\n\n"), pair)(input).unwrap();
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    let pairs = parse(input)?;
     let mut packets = pairs
         .into_iter()
         .flat_map(|pair| [pair.left, pair.right])
@@ -108,36 +115,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         .binary_search(&Packet::List(vec![Packet::Number(6)]))
         .unwrap() as u32
         + 1;
-    Some(div1_index * div2_index)
+    Ok(Some(div1_index * div2_index))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_one(&input), Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_two(&input), Some(140));
-    }
-
-    #[test]
-    #[ignore]
-    fn test_solutions() {
-        let input = advent_of_code::read_file("inputs", 13);
-        assert_eq!(part_one(&input), Some(6235));
-        assert_eq!(part_two(&input), Some(22866));
-    }
-}
+crate::solution!(parse);
@@ -0,0 +1,166 @@
+use std::fmt::Display;
+
+use nom::{bytes::complete::tag, multi::separated_list1};
+
+use crate::helpers::geom::{Direction, Point2};
+use crate::helpers::parse::{lines, point2};
+use crate::helpers::sparse_grid::Coord;
+use crate::helpers::SparseGrid;
+use crate::{run_parser, Error, ParseResult};
+
+fn parse_line(input: &str) -> ParseResult<'_, Vec<Coord>> {
+    separated_list1(tag(" -> "), point2(","))(input)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Block {
+    Rock,
+    Sand,
+    Start,
+}
+
+impl Block {
+    fn symbol(&self) -> char {
+        match self {
+            Block::Rock => '#',
+            Block::Sand => 'o',
+            Block::Start => '+',
+        }
+    }
+}
+
+/// The cave as the blocks that aren't air. Without a floor, sand that falls below the
+/// lowest rock falls into the abyss.
+#[derive(Debug)]
+struct Map {
+    grid: SparseGrid<Block>,
+    start: Coord,
+    /// The depth of the floor, which spans the whole cave.
+    floor_y: Option<i64>,
+    lowest_rock_y: i64,
+}
+
+impl Map {
+    fn new(shapes: Vec<Vec<Coord>>, start: Coord, has_floor: bool) -> Self {
+        let mut grid = SparseGrid::new();
+        for shape in &shapes {
+            for (from, to) in shape.iter().zip(&shape[1..]) {
+                for x in from.x.min(to.x)..=from.x.max(to.x) {
+                    for y in from.y.min(to.y)..=from.y.max(to.y) {
+                        grid.insert(Point2::new(x, y), Block::Rock);
+                    }
+                }
+            }
+        }
+        let lowest_rock_y = grid.bounds().map_or(0, |bounds| bounds.max.y);
+
+        grid.insert(start, Block::Start);
+        Self {
+            grid,
+            start,
+            floor_y: has_floor.then_some(lowest_rock_y + 2),
+            lowest_rock_y,
+        }
+    }
+
+    fn produce_sand(&mut self) -> Option<Coord> {
+        if self.grid.get(self.start) == Some(&Block::Sand) {
+            return None;
+        }
+
+        let mut sand = self.start;
+        loop {
+            match self.advance_sand(sand) {
+                Some(Some(new_pos)) => sand = new_pos,
+                Some(None) => {
+                    self.grid.insert(sand, Block::Sand);
+                    return Some(sand);
+                }
+                None => return None,
+            }
+        }
+    }
+
+    fn is_free(&self, pos: Coord) -> bool {
+        Some(pos.y) != self.floor_y && !self.grid.contains(pos)
+    }
+
+    /// Where the sand at `sand` moves to, `Some(None)` if it comes to rest there, or `None`
+    /// if it falls into the abyss.
+    fn advance_sand(&self, sand: Coord) -> Option<Option<Coord>> {
+        if self.floor_y.is_none() && sand.y > self.lowest_rock_y {
+            return None;
+        }
+        let next = [Direction::Down, Direction::DownLeft, Direction::DownRight]
+            .into_iter()
+            .map(|direction| sand.step(direction))
+            .find(|&pos| self.is_free(pos));
+        Some(next)
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let render = self
+            .grid
+            .render(|_, block| block.map_or('.', Block::symbol));
+        write!(f, "{}", render)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<Coord>>, Error> {
+    run_parser(lines(parse_line), input)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    let shapes = parse(input)?;
+    let mut map = Map::new(shapes, Point2::new(500, 0), false);
+
+    let mut sands = vec![];
+    while let Some(sand) = map.produce_sand() {
+        sands.push(sand);
+    }
+
+    Ok(Some(sands.len() as u32))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    let shapes = parse(input)?;
+    let mut map = Map::new(shapes, Point2::new(500, 0), true);
+
+    let mut sands = vec![];
+    while let Some(sand) = map.produce_sand() {
+        sands.push(sand);
+    }
+
+    Ok(Some(sands.len() as u32))
+}
+
+crate::solution!(parse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let input = crate::read_example(2022, 14, "example").input;
+        let mut map = Map::new(parse(&input).unwrap(), Point2::new(500, 0), false);
+        while map.produce_sand().is_some() {}
+        assert_eq!(
+            map.to_string(),
+            "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+"
+        );
+    }
+}
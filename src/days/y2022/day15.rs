@@ -0,0 +1,132 @@
+use nom::{bytes::complete::tag, error::context, sequence::separated_pair};
+
+use crate::helpers::geom::Point2;
+use crate::helpers::intervals::{Interval, IntervalSet};
+use crate::helpers::parse::{integer, key_value, lines};
+use crate::{run_parser, Error, ParseResult};
+
+type Int = i32;
+type Point = Point2<Int>;
+
+crate::params! {
+    /// The row to count the positions that can't hold a beacon in.
+    row: Int = 2_000_000,
+    /// The distress beacon is within `0..=max_coord` on both axes.
+    max_coord: Int = 4_000_000,
+}
+
+#[derive(Debug)]
+struct Sensor {
+    location: Point,
+    beacon_location: Point,
+    beacon_distance: Int,
+}
+
+impl Sensor {
+    fn new(location: Point, beacon_location: Point) -> Self {
+        let beacon_distance = location.manhattan(beacon_location);
+
+        Self {
+            location,
+            beacon_location,
+            beacon_distance,
+        }
+    }
+
+    fn covered_xrange(&self, y: Int) -> Option<Interval<Int>> {
+        let y_dist = (self.location.y - y).abs();
+        if y_dist > self.beacon_distance {
+            return None;
+        }
+
+        let x_dist = self.beacon_distance - y_dist;
+        let x_start = self.location.x - x_dist;
+        let x_end = self.location.x + x_dist;
+        Some(Interval::new(x_start, x_end))
+    }
+}
+
+fn point(input: &str) -> ParseResult<'_, Point> {
+    let (input, (x, y)) =
+        separated_pair(key_value("x", integer), tag(", "), key_value("y", integer))(input)?;
+    Ok((input, Point2::new(x, y)))
+}
+
+fn sensor(input: &str) -> ParseResult<'_, Sensor> {
+    let (input, _) = context("'Sensor at'", tag("Sensor at "))(input)?;
+    let (input, location) = point(input)?;
+    let (input, _) = context("': closest beacon is at'", tag(": closest beacon is at "))(input)?;
+    let (input, beacon_location) = point(input)?;
+
+    Ok((input, Sensor::new(location, beacon_location)))
+}
+
+fn parse(input: &str) -> Result<Vec<Sensor>, Error> {
+    run_parser(lines(sensor), input)
+}
+
+pub fn part_one(input: &str, params: &Params) -> Result<Option<u32>, Error> {
+    let y = params.row;
+    let sensors = parse(input)?;
+    let mut covered = sensors
+        .iter()
+        .filter_map(|s| s.covered_xrange(y))
+        .collect::<IntervalSet<Int>>();
+    for s in sensors.iter().filter(|s| s.beacon_location.y == y) {
+        covered.remove(Interval::new(s.beacon_location.x, s.beacon_location.x));
+    }
+    Ok(Some(covered.length() as u32))
+}
+
+pub fn part_two(input: &str, params: &Params) -> Result<Option<u64>, Error> {
+    let bounds = Interval::new(0, params.max_coord);
+    let sensors = parse(input)?;
+    let mut covered = IntervalSet::new();
+    for y in 0..=params.max_coord {
+        covered.clear();
+        for range in sensors.iter().filter_map(|s| s.covered_xrange(y)) {
+            covered.insert(range);
+        }
+        if let Some(gap) = covered.gaps(bounds).first() {
+            return Ok(Some((gap.start as u64 * 4000000) + y as u64));
+        }
+    }
+    Ok(None)
+}
+
+crate::solution!(parse, Params);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_covered_xrange() {
+        let s = Sensor {
+            location: Point2::new(0, 0),
+            beacon_distance: 5,
+            beacon_location: Point2::new(2, 3),
+        };
+        assert_eq!(s.covered_xrange(0), Some(Interval::new(-5, 5)));
+        assert_eq!(s.covered_xrange(5), Some(Interval::new(0, 0)));
+        assert_eq!(s.covered_xrange(-2), Some(Interval::new(-3, 3)));
+        assert_eq!(s.covered_xrange(2), Some(Interval::new(-3, 3)));
+
+        assert_eq!(s.covered_xrange(6), None);
+        assert_eq!(s.covered_xrange(-6), None);
+        assert_eq!(s.covered_xrange(600), None);
+        assert_eq!(s.covered_xrange(-600), None);
+    }
+
+    #[test]
+    fn test_beacon_in_other_range() {
+        // the first beacon is also covered by the second sensor, it still holds a beacon.
+        let input = "Sensor at x=0, y=0: closest beacon is at x=1, y=0
+Sensor at x=3, y=0: closest beacon is at x=5, y=0";
+        let params = Params {
+            row: 0,
+            ..Params::default()
+        };
+        assert_eq!(part_one(input, &params), Ok(Some(5)));
+    }
+}
@@ -6,13 +6,13 @@ use std::{
 };
 
 use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{alpha1, line_ending},
+    branch::alt, bytes::complete::tag, character::complete::alpha1, error::context,
     multi::separated_list1,
-    IResult,
 };
 
+use crate::helpers::parse::lines;
+use crate::{run_parser, Error, ParseResult};
+
 // TODO: Performance optimizations! This is by far the slowest solution (part 2 takes ~3 minutes in release)
 
 #[derive(Debug)]
@@ -22,8 +22,8 @@ struct Valve {
     connected_ids: Vec<String>,
 }
 
-fn valve(input: &str) -> IResult<&str, Valve> {
-    let (input, _) = tag("Valve ")(input)?;
+fn valve(input: &str) -> ParseResult<'_, Valve> {
+    let (input, _) = context("'Valve'", tag("Valve "))(input)?;
     let (input, id) = alpha1(input)?;
     let (input, _) = tag(" has flow rate=")(input)?;
     let (input, flow_rate) = nom::character::complete::u32(input)?;
@@ -165,13 +165,10 @@ fn shortcuts(start: &String, valves: &HashMap<String, Valve>) -> HashMap<String,
     paths
 }
 
-fn parse(
-    input: &str,
-) -> (
-    HashMap<String, Valve>,
-    HashMap<String, HashMap<String, u32>>,
-) {
-    let (_, valves) = separated_list1(line_ending, valve)(input).unwrap();
+type Shortcuts = HashMap<String, HashMap<String, u32>>;
+
+fn parse(input: &str) -> Result<(HashMap<String, Valve>, Shortcuts), Error> {
+    let valves = run_parser(lines(valve), input)?;
     let mut valve_map = HashMap::new();
     let mut shortcuts_map = HashMap::new();
     for valve in &valves {
@@ -187,11 +184,11 @@ fn parse(
     for v in valves {
         shortcuts_map.insert(v.id.clone(), shortcuts(&v.id, &valve_map));
     }
-    (valve_map, shortcuts_map)
+    Ok((valve_map, shortcuts_map))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (valve_map, shortcuts_map) = parse(input);
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    let (valve_map, shortcuts_map) = parse(input)?;
 
     let walk = Walk {
         location: "AA".to_string(),
@@ -200,11 +197,16 @@ pub fn part_one(input: &str) -> Option<u32> {
         helper: false,
     };
 
-    Some(bfs(&walk, &valve_map, &shortcuts_map, &mut HashMap::new()))
+    Ok(Some(bfs(
+        &walk,
+        &valve_map,
+        &shortcuts_map,
+        &mut HashMap::new(),
+    )))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (valve_map, shortcuts_map) = parse(input);
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    let (valve_map, shortcuts_map) = parse(input)?;
 
     let walk = Walk {
         location: "AA".to_string(),
@@ -213,36 +215,12 @@ pub fn part_two(input: &str) -> Option<u32> {
         helper: true,
     };
 
-    Some(bfs(&walk, &valve_map, &shortcuts_map, &mut HashMap::new()))
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 16);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    Ok(Some(bfs(
+        &walk,
+        &valve_map,
+        &shortcuts_map,
+        &mut HashMap::new(),
+    )))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 16);
-        assert_eq!(part_one(&input), Some(1651));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 16);
-        assert_eq!(part_two(&input), Some(1707));
-    }
-
-    #[test]
-    #[ignore]
-    fn test_solutions() {
-        let input = advent_of_code::read_file("inputs", 16);
-        assert_eq!(part_one(&input), Some(1595));
-        assert_eq!(part_two(&input), Some(2189));
-    }
-}
+crate::solution!(parse);
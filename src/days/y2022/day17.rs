@@ -2,6 +2,12 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
+crate::params! {
+    /// The number of rocks to drop.
+    iterations: u64 = 2022,
+    iterations_part_two: u64 = 1_000_000_000_000,
+}
+
 #[derive(Clone, Debug)]
 enum Move {
     Left,
@@ -91,7 +97,9 @@ impl Rock<'_> {
 
 #[derive(Debug)]
 struct Tower {
-    grid: Vec<u16>,
+    /// Rows from the floor up as bits, with the walls and the leftmost cell as the highest
+    /// bit, so a rock collides with a row in a single `&`.
+    rows: Vec<u16>,
     move_list: Vec<Move>,
     height: u64,
 }
@@ -99,10 +107,18 @@ struct Tower {
 const LOOKBACK: usize = 15;
 
 impl Tower {
+    fn new(rows: usize, move_list: Vec<Move>) -> Self {
+        Tower {
+            rows: vec![EMPTY_ROW; rows],
+            move_list,
+            height: 0,
+        }
+    }
+
     fn pattern_state(&self) -> u128 {
         let bit_width = 9;
         (1..=LOOKBACK).fold(0u128, |acc, i| {
-            acc | (self.grid[self.height as usize - i] as u128) << (bit_width * (LOOKBACK - i))
+            acc | (self.rows[self.height as usize - i] as u128) << (bit_width * (LOOKBACK - i))
         })
     }
 
@@ -113,8 +129,8 @@ impl Tower {
             point: (3, self.height as usize + shape_height + 3),
         };
 
-        while self.grid.len() <= r.point.1 + 1 {
-            self.grid.push(EMPTY_ROW);
+        while self.rows.len() <= r.point.1 + 1 {
+            self.rows.push(EMPTY_ROW);
         }
 
         loop {
@@ -144,7 +160,7 @@ impl Tower {
                 Move::Left => row << 1,
                 Move::Right => row >> 1,
             };
-            if self.grid[y] & target != 0 {
+            if self.rows[y] & target != 0 {
                 can_move = false;
                 break;
             }
@@ -161,7 +177,7 @@ impl Tower {
                 continue;
             }
             if let Some(y) = r.point.1.checked_sub(i) {
-                self.grid[y] |= row;
+                self.rows[y] |= row;
             }
         }
     }
@@ -175,7 +191,7 @@ impl Tower {
         let test_ys = y - r.height() - 1..=y - 1;
         let mut can_move = true;
         for test_y in test_ys {
-            let grid_bits = self.grid[test_y];
+            let grid_bits = self.rows[test_y];
             if let Some(rock_bits) = r.row_at_y(test_y + 1) {
                 if grid_bits & rock_bits != 0 {
                     can_move = false;
@@ -208,12 +224,8 @@ fn run_sim(input: &str, iterations: u64) -> Option<u64> {
     let mut move_index = 0usize;
     let mut shape_index = 0usize;
 
-    let mut tower = Tower {
-        grid: vec![EMPTY_ROW; 7],
-        move_list,
-        height: 0,
-    };
-    tower.grid[0] = u16::MAX;
+    let mut tower = Tower::new(7, move_list);
+    tower.rows[0] = u16::MAX;
 
     let mut patterns = HashMap::new();
     let mut rocks_spawned = 0;
@@ -272,44 +284,20 @@ fn run_sim(input: &str, iterations: u64) -> Option<u64> {
     Some(tower.height)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    run_sim(input, 2022)
+pub fn part_one(input: &str, params: &Params) -> Option<u64> {
+    run_sim(input, params.iterations)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    run_sim(input, 1000000000000)
+pub fn part_two(input: &str, params: &Params) -> Option<u64> {
+    run_sim(input, params.iterations_part_two)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 17);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+crate::solution!(parse_moves, Params);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 17);
-        assert_eq!(part_one(&input), Some(3068));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 17);
-        assert_eq!(part_two(&input), Some(1514285714288));
-    }
-
-    #[test]
-    #[ignore]
-    fn test_solutions() {
-        let input = advent_of_code::read_file("inputs", 17);
-        assert_eq!(part_one(&input), Some(3059));
-        assert_eq!(part_two(&input), Some(1500874635587));
-    }
-
     #[test]
     fn test_line() {
         let mut r = Rock {
@@ -339,11 +327,7 @@ mod tests {
         r.point.1 -= 2;
         assert_eq!(r.shifted_bits(), vec![0b000011110, 0, 0, 0]);
 
-        let tower = Tower {
-            grid: vec![EMPTY_ROW; 5],
-            move_list: vec![],
-            height: 0,
-        };
+        let tower = Tower::new(5, vec![]);
 
         // this should bump the right edge and not allow the move
         assert_eq!(r.point, (4, 2));
@@ -395,12 +379,8 @@ mod tests {
             vec![0b000001000, 0b000011100, 0b000001000, 0]
         );
 
-        let mut tower = Tower {
-            grid: vec![EMPTY_ROW; 5],
-            move_list: vec![],
-            height: 0,
-        };
-        tower.grid[0] = u16::MAX;
+        let mut tower = Tower::new(5, vec![]);
+        tower.rows[0] = u16::MAX;
 
         tower.perform_move(&mut r, &Move::Right);
         assert_eq!(r.point, (5, 3));
@@ -420,7 +400,7 @@ mod tests {
             point: (2, 3),
         };
         tower.perform_move(&mut angle, &Move::Right);
-        assert_eq!(tower.move_down(&mut angle), false);
+        assert!(!tower.move_down(&mut angle));
         tower.apply_move(&angle);
         assert_eq!(angle.point, (3, 3));
 
@@ -432,7 +412,7 @@ mod tests {
         assert_eq!(r.point, (2, 4));
 
         // this should bump into the lower part of the angle
-        assert_eq!(tower.move_down(&mut r), false);
+        assert!(!tower.move_down(&mut r));
         assert_eq!(r.point, (2, 4));
 
         // this should be successful
@@ -440,16 +420,16 @@ mod tests {
         assert_eq!(r.point, (1, 4));
 
         // as should this
-        assert_eq!(tower.move_down(&mut r), true);
+        assert!(tower.move_down(&mut r));
         assert_eq!(r.point, (1, 3));
 
         // now we should hit the floor (and the angle, technically)
-        assert_eq!(tower.move_down(&mut r), false);
+        assert!(!tower.move_down(&mut r));
         tower.apply_move(&r);
 
-        assert_eq!(tower.grid[3], 0b101001001);
-        assert_eq!(tower.grid[2], 0b111101001);
-        assert_eq!(tower.grid[1], 0b101111001);
+        assert_eq!(tower.rows[3], 0b101001001);
+        assert_eq!(tower.rows[2], 0b111101001);
+        assert_eq!(tower.rows[1], 0b101111001);
     }
 
     #[test]
@@ -490,12 +470,8 @@ mod tests {
             vec![0b000000100, 0b000000100, 0b000011100, 0]
         );
 
-        let mut tower = Tower {
-            grid: vec![EMPTY_ROW; 5],
-            move_list: vec![],
-            height: 0,
-        };
-        tower.grid[0] = u16::MAX;
+        let mut tower = Tower::new(5, vec![]);
+        tower.rows[0] = u16::MAX;
 
         tower.perform_move(&mut r, &Move::Right);
         assert_eq!(r.point, (5, 3));
@@ -548,12 +524,8 @@ mod tests {
             vec![0b000010000, 0b000010000, 0b000010000, 0b000010000]
         );
 
-        let mut tower = Tower {
-            grid: vec![EMPTY_ROW; 6],
-            move_list: vec![],
-            height: 0,
-        };
-        tower.grid[0] = u16::MAX;
+        let mut tower = Tower::new(6, vec![]);
+        tower.rows[0] = u16::MAX;
 
         r.point.0 = 6;
         tower.perform_move(&mut r, &Move::Right);
@@ -607,12 +579,8 @@ mod tests {
             vec![0b000011000, 0b000011000, 0b000000000, 0b000000000]
         );
 
-        let mut tower = Tower {
-            grid: vec![EMPTY_ROW; 6],
-            move_list: vec![],
-            height: 0,
-        };
-        tower.grid[0] = u16::MAX;
+        let mut tower = Tower::new(6, vec![]);
+        tower.rows[0] = u16::MAX;
 
         r.point.0 = 5;
         tower.perform_move(&mut r, &Move::Right);
@@ -630,11 +598,7 @@ mod tests {
 
     #[test]
     fn test_tower() {
-        let mut tower = Tower {
-            grid: vec![EMPTY_ROW; 5],
-            move_list: vec![],
-            height: 0,
-        };
+        let mut tower = Tower::new(5, vec![]);
         let mut r = Rock {
             shape: &Shape::Line,
             point: (3, 4),
@@ -643,32 +607,32 @@ mod tests {
         tower.perform_move(&mut r, &Move::Right);
         assert_eq!(r.point, (4, 4));
 
-        assert_eq!(tower.move_down(&mut r), true);
+        assert!(tower.move_down(&mut r));
         assert_eq!(r.point, (4, 3));
 
         tower.perform_move(&mut r, &Move::Right);
         assert_eq!(r.point, (4, 3));
 
-        assert_eq!(tower.move_down(&mut r), true);
+        assert!(tower.move_down(&mut r));
         assert_eq!(r.point, (4, 2));
 
         tower.perform_move(&mut r, &Move::Right);
         assert_eq!(r.point, (4, 2));
 
-        assert_eq!(tower.move_down(&mut r), true);
+        assert!(tower.move_down(&mut r));
         assert_eq!(r.point, (4, 1));
 
         tower.perform_move(&mut r, &Move::Left);
         assert_eq!(r.point, (3, 1));
 
-        assert_eq!(tower.move_down(&mut r), false);
+        assert!(!tower.move_down(&mut r));
         assert_eq!(r.point, (3, 1));
 
-        tower.apply_move(&mut r);
-        assert_eq!(tower.grid[1], 0b100111101);
+        tower.apply_move(&r);
+        assert_eq!(tower.rows[1], 0b100111101);
 
         for _ in 1..=6 {
-            tower.grid.push(0);
+            tower.rows.push(0);
         }
 
         r = Rock {
@@ -678,38 +642,34 @@ mod tests {
 
         tower.perform_move(&mut r, &Move::Left);
         assert_eq!(r.point, (2, 7));
-        assert_eq!(tower.move_down(&mut r), true);
+        assert!(tower.move_down(&mut r));
         assert_eq!(r.point, (2, 6));
 
         tower.perform_move(&mut r, &Move::Right);
         assert_eq!(r.point, (3, 6));
-        assert_eq!(tower.move_down(&mut r), true);
+        assert!(tower.move_down(&mut r));
         assert_eq!(r.point, (3, 5));
 
         tower.perform_move(&mut r, &Move::Left);
         assert_eq!(r.point, (2, 5));
-        assert_eq!(tower.move_down(&mut r), true);
+        assert!(tower.move_down(&mut r));
         assert_eq!(r.point, (2, 4));
 
         tower.perform_move(&mut r, &Move::Right);
         assert_eq!(r.point, (3, 4));
-        assert_eq!(tower.move_down(&mut r), false);
+        assert!(!tower.move_down(&mut r));
         assert_eq!(r.point, (3, 4));
 
-        tower.apply_move(&mut r);
-        assert_eq!(tower.grid[1], 0b100111101);
-        assert_eq!(tower.grid[2], 0b100010001);
-        assert_eq!(tower.grid[3], 0b100111001);
-        assert_eq!(tower.grid[4], 0b100010001);
+        tower.apply_move(&r);
+        assert_eq!(tower.rows[1], 0b100111101);
+        assert_eq!(tower.rows[2], 0b100010001);
+        assert_eq!(tower.rows[3], 0b100111001);
+        assert_eq!(tower.rows[4], 0b100010001);
     }
 
     #[test]
     fn test_tower_edges() {
-        let mut tower = Tower {
-            grid: vec![EMPTY_ROW; 6],
-            move_list: vec![],
-            height: 0,
-        };
+        let mut tower = Tower::new(6, vec![]);
         let mut r = Rock {
             shape: &Shape::Angle,
             point: (3, 4),
@@ -732,8 +692,8 @@ mod tests {
         tower.perform_move(&mut r, &Move::Right);
         assert_eq!(r.point, (5, 4));
         tower.apply_move(&r);
-        assert_eq!(tower.grid[4], 0b100000011);
-        assert_eq!(tower.grid[3], 0b100000011);
-        assert_eq!(tower.grid[2], 0b100001111);
+        assert_eq!(tower.rows[4], 0b100000011);
+        assert_eq!(tower.rows[3], 0b100000011);
+        assert_eq!(tower.rows[2], 0b100001111);
     }
 }
@@ -0,0 +1,60 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::helpers::geom::{Cuboid, Point3};
+use crate::helpers::parse::{lines, point3};
+use crate::{run_parser, Error};
+
+type Int = i16;
+type Point = Point3<Int>;
+
+fn parse(input: &str) -> Result<Vec<Point>, Error> {
+    run_parser(lines(point3(",")), input)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    let points_list = parse(input)?;
+    let points: HashSet<Point> = HashSet::from_iter(points_list.iter().cloned());
+
+    let result = points.iter().fold(points.len() * 6, |acc, point| {
+        let existing = point.neighbors().filter(|p| !points.contains(p)).count();
+        acc - (6 - existing)
+    });
+
+    Ok(Some(result as u32))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    let points_list = parse(input)?;
+
+    let points: HashSet<Point> = HashSet::from_iter(points_list.iter().cloned());
+
+    // a layer of air around the droplet, so the steam can reach every side of it.
+    let bounds = Cuboid::enclosing(points.iter().copied()).unwrap().grow(1);
+
+    let mut seen = HashSet::new();
+    let mut to_see = VecDeque::new();
+    to_see.push_back(bounds.min);
+
+    let mut sides = 0;
+    while let Some(coord) = to_see.pop_front() {
+        if !seen.insert(coord) {
+            continue;
+        }
+
+        for adjacent in coord.neighbors() {
+            if !bounds.contains(adjacent) {
+                continue;
+            }
+
+            if points.contains(&adjacent) {
+                sides += 1;
+            } else {
+                to_see.push_back(adjacent);
+            }
+        }
+    }
+
+    Ok(Some(sides as u32))
+}
+
+crate::solution!(parse);
@@ -1,13 +1,21 @@
 use std::collections::HashMap;
 
-use nom::{
-    bytes::complete::tag, character::complete::line_ending, multi::separated_list1, IResult,
-};
+use nom::{bytes::complete::tag, error::context};
+
+use crate::helpers::parse::lines;
+use crate::{run_parser, Error, ParseResult};
 use rayon::{iter::ParallelIterator, prelude::IntoParallelRefIterator};
 
-fn parse_blueprint(input: &str) -> IResult<&str, Blueprint> {
+crate::params! {
+    minutes: u32 = 24,
+    minutes_part_two: u32 = 32,
+    /// Part two only uses the first few blueprints, the elephants ate the rest.
+    blueprints_part_two: usize = 3,
+}
+
+fn parse_blueprint(input: &str) -> ParseResult<'_, Blueprint> {
     use nom::character::complete::u32;
-    let (input, _) = tag("Blueprint ")(input)?;
+    let (input, _) = context("'Blueprint'", tag("Blueprint "))(input)?;
     let (input, id) = u32(input)?;
     let (input, _) = tag(": Each ore robot costs ")(input)?;
     let (input, ore_cost) = u32(input)?;
@@ -189,16 +197,8 @@ impl Blueprint {
                 max_result = max_result.max(self.max_geodes(updated_res, cache, time_map));
             } else {
                 // could we build one if we waited?
-                let needed_ore = if res.ore < cost.0 {
-                    cost.0 - res.ore
-                } else {
-                    0
-                };
-                let needed_clay = if res.clay < cost.1 {
-                    cost.1 - res.clay
-                } else {
-                    0
-                };
+                let needed_ore = cost.0.saturating_sub(res.ore);
+                let needed_clay = cost.1.saturating_sub(res.clay);
                 let ore_wait = (needed_ore as f32 / res.ore_bots as f32).ceil() as u32;
                 let clay_wait = (needed_clay as f32 / res.clay_bots as f32).ceil() as u32;
                 let wait = ore_wait.max(clay_wait);
@@ -218,16 +218,8 @@ impl Blueprint {
             max_result = max_result.max(self.max_geodes(updated_res, cache, time_map));
         } else {
             // could we build one if we waited?
-            let needed_ore = if res.ore < cost.0 {
-                cost.0 - res.ore
-            } else {
-                0
-            };
-            let needed_obsidian = if res.obsidian < cost.1 {
-                cost.1 - res.obsidian
-            } else {
-                0
-            };
+            let needed_ore = cost.0.saturating_sub(res.ore);
+            let needed_obsidian = cost.1.saturating_sub(res.obsidian);
             let ore_wait = (needed_ore as f32 / res.ore_bots as f32).ceil() as u32;
             let obsidian_wait = (needed_obsidian as f32 / res.obsidian_bots as f32).ceil() as u32;
             let wait = ore_wait.max(obsidian_wait);
@@ -254,10 +246,14 @@ impl Blueprint {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (_, blueprints) = separated_list1(line_ending, parse_blueprint)(input).unwrap();
+fn parse(input: &str) -> Result<Vec<Blueprint>, Error> {
+    run_parser(lines(parse_blueprint), input)
+}
+
+pub fn part_one(input: &str, params: &Params) -> Result<Option<u32>, Error> {
+    let blueprints = parse(input)?;
 
-    let duration = 24;
+    let duration = params.minutes;
     let result = blueprints
         .par_iter()
         .map(|blueprint| {
@@ -268,14 +264,14 @@ pub fn part_one(input: &str) -> Option<u32> {
             blueprint.quality(resources)
         })
         .sum();
-    Some(result)
+    Ok(Some(result))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (_, mut blueprints) = separated_list1(line_ending, parse_blueprint)(input).unwrap();
-    blueprints.truncate(3);
+pub fn part_two(input: &str, params: &Params) -> Result<Option<u32>, Error> {
+    let mut blueprints = parse(input)?;
+    blueprints.truncate(params.blueprints_part_two);
 
-    let duration = 32;
+    let duration = params.minutes_part_two;
     let result = blueprints
         .par_iter()
         .map(|blueprint| {
@@ -288,36 +284,7 @@ pub fn part_two(input: &str) -> Option<u32> {
             blueprint.max_geodes(resources, &mut cache, &mut time_map)
         })
         .product();
-    Some(result)
+    Ok(Some(result))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 19);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 19);
-        assert_eq!(part_one(&input), Some(33));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 19);
-        assert_eq!(part_two(&input), Some(56 * 62));
-    }
-
-    #[test]
-    #[ignore]
-    fn test_solutions() {
-        let input = advent_of_code::read_file("inputs", 19);
-        assert_eq!(part_one(&input), Some(1294));
-        assert_eq!(part_two(&input), Some(13640));
-    }
-}
+crate::solution!(parse, Params);
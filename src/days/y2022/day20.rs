@@ -0,0 +1,54 @@
+use itertools::Itertools;
+
+use crate::helpers::parse::{integer, lines};
+use crate::{run_parser, Error};
+
+fn parse_numbers(input: &str) -> Result<Vec<(usize, i64)>, Error> {
+    let numbers = run_parser(lines(integer::<i64>), input)?;
+    Ok(numbers.into_iter().enumerate().collect_vec())
+}
+
+fn coordinates(mixed: Vec<(usize, i64)>) -> Option<i64> {
+    let mut result = [0i64; 3];
+    if let Some(zero_i) = mixed.iter().position(|n| n.1 == 0) {
+        result[0] = mixed[zero_i.wrapping_add(1000).rem_euclid(mixed.len())].1;
+        result[1] = mixed[zero_i.wrapping_add(2000).rem_euclid(mixed.len())].1;
+        result[2] = mixed[zero_i.wrapping_add(3000).rem_euclid(mixed.len())].1;
+    }
+    Some(result.iter().sum())
+}
+
+pub fn part_one(input: &str) -> Result<Option<i64>, Error> {
+    let numbers = parse_numbers(input)?;
+
+    let mut mixed = numbers.clone();
+    for n in numbers {
+        if let Some(i) = mixed.iter().position(|m| m.0 == n.0) {
+            let removed = mixed.remove(i);
+            let next_i = (i as i64 + removed.1).rem_euclid(mixed.len() as i64);
+            mixed.insert(next_i as usize, removed);
+        }
+    }
+
+    Ok(coordinates(mixed))
+}
+
+pub fn part_two(input: &str) -> Result<Option<i64>, Error> {
+    let mut numbers = parse_numbers(input)?;
+    numbers.iter_mut().for_each(|n| n.1 *= 811589153);
+
+    let mut mixed = numbers.clone();
+    for _ in 0..10 {
+        for n in numbers.iter() {
+            if let Some(i) = mixed.iter().position(|m| m.0 == n.0) {
+                let removed = mixed.remove(i);
+                let next_i = (i as i64 + removed.1).rem_euclid(mixed.len() as i64);
+                mixed.insert(next_i as usize, removed);
+            }
+        }
+    }
+
+    Ok(coordinates(mixed))
+}
+
+crate::solution!(parse_numbers);
@@ -0,0 +1,150 @@
+/*
+ * Registry of the solved days of 2022. Add new days here after scaffolding them.
+ */
+use super::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+
+const YEAR: u16 = 2022;
+
+pub static DAYS: &[Day] = &[
+    Day {
+        year: YEAR,
+        day: 1,
+        solution: &day01::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 2,
+        solution: &day02::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 3,
+        solution: &day03::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 4,
+        solution: &day04::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 5,
+        solution: &day05::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 6,
+        solution: &day06::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 7,
+        solution: &day07::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 8,
+        solution: &day08::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 9,
+        solution: &day09::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 10,
+        solution: &day10::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 11,
+        solution: &day11::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 12,
+        solution: &day12::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 13,
+        solution: &day13::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 14,
+        solution: &day14::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 15,
+        solution: &day15::Solver,
+        slow: true,
+    },
+    Day {
+        year: YEAR,
+        day: 16,
+        solution: &day16::Solver,
+        slow: true,
+    },
+    Day {
+        year: YEAR,
+        day: 17,
+        solution: &day17::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 18,
+        solution: &day18::Solver,
+        slow: false,
+    },
+    Day {
+        year: YEAR,
+        day: 19,
+        solution: &day19::Solver,
+        slow: true,
+    },
+    Day {
+        year: YEAR,
+        day: 20,
+        solution: &day20::Solver,
+        slow: false,
+    },
+];
@@ -0,0 +1,474 @@
+/*
+ * Points, directions and bounding boxes in two and three dimensions.
+ *
+ * Coordinates are signed integers. In 2D, `y` grows downwards like in `Grid`, so `Up` is
+ * `(0, -1)` and turning right goes from `Up` to `Right` to `Down`.
+ */
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The integer types points can be made of.
+pub trait Scalar:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// `self + other`, or `None` if it overflows.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn abs(self) -> Self {
+        if self < Self::ZERO {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// `-1`, `0` or `1` depending on the sign.
+    fn signum(self) -> Self {
+        match self.cmp(&Self::ZERO) {
+            std::cmp::Ordering::Less => -Self::ONE,
+            std::cmp::Ordering::Equal => Self::ZERO,
+            std::cmp::Ordering::Greater => Self::ONE,
+        }
+    }
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {
+        $(impl Scalar for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+        })*
+    };
+}
+
+impl_scalar!(i8, i16, i32, i64, isize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+
+    /// The change of `(x, y)` for one step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (1, -1),
+            Direction::DownLeft => (-1, 1),
+            Direction::DownRight => (1, 1),
+        }
+    }
+
+    /// The direction with this offset, if there is one.
+    pub fn from_offset(offset: (isize, isize)) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == offset)
+    }
+
+    /// One step in this direction as a point.
+    pub fn vector<T: Scalar>(self) -> Point2<T> {
+        let unit = |d: isize| match d {
+            -1 => -T::ONE,
+            0 => T::ZERO,
+            _ => T::ONE,
+        };
+        let (dx, dy) = self.offset();
+        Point2::new(unit(dx), unit(dy))
+    }
+
+    /// The direction a quarter turn clockwise from this one.
+    pub fn turn_right(self) -> Direction {
+        let (dx, dy) = self.offset();
+        Direction::from_offset((-dy, dx)).unwrap()
+    }
+
+    /// The direction a quarter turn counter-clockwise from this one.
+    pub fn turn_left(self) -> Direction {
+        let (dx, dy) = self.offset();
+        Direction::from_offset((dy, -dx)).unwrap()
+    }
+
+    pub fn opposite(self) -> Direction {
+        let (dx, dy) = self.offset();
+        Direction::from_offset((-dx, -dy)).unwrap()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Component-wise arithmetic, and scaling by a scalar.
+macro_rules! impl_ops {
+    ($point:ident { $($c:ident),* }) => {
+        impl<T: Scalar> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($c: self.$c + other.$c),* }
+            }
+        }
+
+        impl<T: Scalar> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($c: self.$c - other.$c),* }
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($c: self.$c * factor),* }
+            }
+        }
+
+        impl<T: Scalar> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($c: -self.$c),* }
+            }
+        }
+
+        impl<T: Scalar> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Scalar> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl<T: Scalar> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+
+    /// The distance when moving only orthogonally.
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The distance when diagonal moves count as one step, like a king in chess.
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The point with every coordinate replaced by its sign, e.g. to step towards another.
+    pub fn signum(self) -> Self {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.vector()
+    }
+
+    /// The four points next to this one in the orthogonal directions.
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// The eight points around this one, including the diagonal ones.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// This point rotated a quarter turn clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// This point rotated a quarter turn counter-clockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Point2::new(self.y, -self.x)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Scalar> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(self) -> Self {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// The six points that share a face with this one.
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            Point3::new(one, zero, zero),
+            Point3::new(-one, zero, zero),
+            Point3::new(zero, one, zero),
+            Point3::new(zero, -one, zero),
+            Point3::new(zero, zero, one),
+            Point3::new(zero, zero, -one),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+/// An inclusive rectangle of points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rect<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Scalar> Rect<T> {
+    /// The rectangle of a single point.
+    pub fn at(point: Point2<T>) -> Self {
+        Rect {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The smallest rectangle that contains all `points`, `None` if there are none.
+    pub fn enclosing(points: impl IntoIterator<Item = Point2<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = Rect::at(points.next()?);
+        Some(points.fold(first, Rect::expand))
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    pub fn contains(&self, point: Point2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// The smallest rectangle that contains this one and `point`.
+    pub fn expand(self, point: Point2<T>) -> Self {
+        Rect {
+            min: Point2::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            max: Point2::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        }
+    }
+
+    /// This rectangle with `margin` added on every side.
+    pub fn grow(self, margin: T) -> Self {
+        let margin = Point2::new(margin, margin);
+        Rect {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+}
+
+/// An inclusive box of points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cuboid<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+impl<T: Scalar> Cuboid<T> {
+    pub fn at(point: Point3<T>) -> Self {
+        Cuboid {
+            min: point,
+            max: point,
+        }
+    }
+
+    pub fn enclosing(points: impl IntoIterator<Item = Point3<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = Cuboid::at(points.next()?);
+        Some(points.fold(first, Cuboid::expand))
+    }
+
+    pub fn contains(&self, point: Point3<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    pub fn expand(self, point: Point3<T>) -> Self {
+        Cuboid {
+            min: Point3::new(
+                self.min.x.min(point.x),
+                self.min.y.min(point.y),
+                self.min.z.min(point.z),
+            ),
+            max: Point3::new(
+                self.max.x.max(point.x),
+                self.max.y.max(point.y),
+                self.max.z.max(point.z),
+            ),
+        }
+    }
+
+    pub fn grow(self, margin: T) -> Self {
+        let margin = Point3::new(margin, margin, margin);
+        Cuboid {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point2() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 4);
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        assert_eq!(a.step(Direction::UpLeft), Point2::new(0, -3));
+        assert_eq!(a.rotate_right(), Point2::new(2, 1));
+        assert_eq!(a.rotate_right().rotate_left(), a);
+
+        let origin = Point2::<i32>::default();
+        assert_eq!(
+            origin.neighbors().collect::<Vec<_>>(),
+            vec![(0, -1).into(), (0, 1).into(), (-1, 0).into(), (1, 0).into()]
+        );
+        assert!(origin.neighbors8().all(|p| origin.chebyshev(p) == 1));
+    }
+
+    #[test]
+    fn test_point3() {
+        let a = Point3::new(1, 2, 3);
+        let mut b = Point3::new(-1, 0, 5);
+        b += a;
+        assert_eq!(b, Point3::new(0, 2, 8));
+        assert_eq!(a.manhattan(b), 6);
+        assert_eq!(a.chebyshev(b), 5);
+        assert_eq!(a.neighbors().count(), 6);
+        assert!(a.neighbors().all(|p| a.manhattan(p) == 1));
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Right.turn_right(), Direction::Down);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::UpRight);
+        assert_eq!(Direction::Left.turn_left(), Direction::Down);
+        assert_eq!(Direction::DownLeft.opposite(), Direction::UpRight);
+        assert_eq!(Direction::Up.vector::<i64>(), Point2::new(0, -1));
+        assert_eq!(
+            Direction::Left.vector::<i8>().rotate_right(),
+            Direction::Up.vector()
+        );
+    }
+
+    #[test]
+    fn test_bounds() {
+        let rect = Rect::enclosing([(2, 3), (-1, 5), (0, 0)].map(Point2::from)).unwrap();
+        assert_eq!(rect.min, Point2::new(-1, 0));
+        assert_eq!(rect.max, Point2::new(2, 5));
+        assert_eq!((rect.width(), rect.height()), (4, 6));
+        assert!(rect.contains(Point2::new(0, 5)));
+        assert!(!rect.contains(Point2::new(3, 5)));
+        assert!(rect.grow(1).contains(Point2::new(3, 6)));
+        assert_eq!(Rect::<i32>::enclosing([]), None);
+
+        let cuboid = Cuboid::at(Point3::new(0, 0, 0)).expand(Point3::new(2, -2, 1));
+        assert!(cuboid.contains(Point3::new(1, -1, 1)));
+        assert!(!cuboid.contains(Point3::new(1, 1, 1)));
+        assert_eq!(cuboid.grow(1).min, Point3::new(-1, -3, -1));
+    }
+}
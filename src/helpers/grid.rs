@@ -0,0 +1,338 @@
+/*
+ * A rectangular grid of cells, for the puzzles that come as a map of characters.
+ *
+ * Positions are `(x, y)` pairs with the origin in the top left corner and `y` growing
+ * downwards, the way the input reads.
+ */
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+pub use super::geom::Direction;
+use crate::Error;
+
+pub type Pos = (usize, usize);
+
+/// Cells stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+/// An empty grid, for adding rows with [`Grid::push_row`].
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Grid {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with a line per row, turning each character into a cell with `cell`.
+    /// Characters it returns `None` for and rows of another length than the first one are
+    /// reported as errors, with `expected` describing a valid cell.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, Error> {
+        let mut grid = Grid::default();
+        for line in input.trim_end().lines() {
+            // positions of errors are offsets into `input`, which `line` is a slice of.
+            let offset = line.as_ptr() as usize - input.as_ptr() as usize;
+            let row_start = grid.cells.len();
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => grid.cells.push(value),
+                    None => return Err(Error::parse(input, &input[offset + i..], expected)),
+                }
+            }
+
+            let width = grid.cells.len() - row_start;
+            if grid.height == 0 {
+                grid.width = width;
+            } else if width != grid.width {
+                let column = line
+                    .char_indices()
+                    .nth(grid.width)
+                    .map_or(line.len(), |(i, _)| i);
+                let expected = if width < grid.width {
+                    format!("a row of {} cells", grid.width)
+                } else {
+                    "end of the row".to_string()
+                };
+                return Err(Error::parse(input, &input[offset + column..], expected));
+            }
+            grid.height += 1;
+        }
+
+        match grid.height {
+            0 => Err(Error::parse(input, "", "a grid")),
+            _ => Ok(grid),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.in_bounds(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// Adds a row below the last one, e.g. for a tower that grows. Panics if the row isn't
+    /// as wide as the grid.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let row_start = self.cells.len();
+        self.cells.extend(row);
+        let width = self.cells.len() - row_start;
+        if self.height == 0 && self.width == 0 {
+            self.width = width;
+        }
+        assert_eq!(width, self.width, "rows need to be as wide as the grid");
+        self.height += 1;
+    }
+
+    /// The position one step from `pos` in `direction`, if it is in the grid.
+    pub fn step(&self, (x, y): Pos, direction: Direction) -> Option<Pos> {
+        let (dx, dy) = direction.offset();
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    /// The cells from `pos` up to the edge of the grid in `direction`, without `pos` itself.
+    pub fn ray(&self, pos: Pos, direction: Direction) -> impl Iterator<Item = (Pos, &T)> {
+        std::iter::successors(self.step(pos, direction), move |&pos| {
+            self.step(pos, direction)
+        })
+        .map(|pos| (pos, &self[pos]))
+    }
+
+    /// The positions next to `pos` in the four orthogonal directions that are in the grid.
+    pub fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The positions around `pos`, including the diagonal ones, that are in the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        assert!(self.in_bounds((x, y)), "({}, {}) is outside the grid", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        assert!(self.in_bounds((x, y)), "({}, {}) is outside the grid", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Prints the cells of each row next to each other, with a line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+30373
+25512
+65332
+";
+
+    fn digits() -> Grid<u32> {
+        Grid::parse(MAP, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (5, 3));
+        assert_eq!(grid[(3, 0)], 7);
+        assert_eq!(grid.to_string(), MAP);
+
+        let error = Grid::parse("123\n1x3\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 2, col 2: expected a digit"
+        );
+        let error = Grid::parse("123\n12\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 2, col 3: expected a row of 3 cells"
+        );
+        let error = Grid::parse("123\n1234\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 2, col 4: expected end of the row"
+        );
+        assert!(Grid::parse("\n", "a digit", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = digits();
+        assert_eq!(grid.get((4, 2)), Some(&2));
+        assert_eq!(grid.get((5, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        *grid.get_mut((0, 0)).unwrap() = 9;
+        grid[(1, 0)] = 8;
+        assert_eq!(grid.row(0), &[9, 8, 3, 7, 3]);
+        assert_eq!(grid.column(4).copied().collect::<Vec<_>>(), vec![3, 2, 2]);
+        assert_eq!(grid.columns().count(), 5);
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.position(|&cell| cell == 5), Some((1, 1)));
+        assert_eq!(grid.iter().nth(6), Some(((1, 1), &5)));
+
+        grid.push_row([1, 1, 1, 1, 1]);
+        assert_eq!(grid.height(), 4);
+        assert_eq!(
+            grid.map(|&cell| cell > 5)
+                .iter()
+                .filter(|(_, &c)| c)
+                .count(),
+            4
+        );
+        assert_eq!(Grid::from_fn(2, 2, |(x, y)| x + y).row(1), &[1, 2]);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(
+            grid.neighbors((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+        assert_eq!(grid.step((2, 1), Direction::Right), None);
+        assert_eq!(grid.step((2, 1), Direction::UpLeft), Some((1, 0)));
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = digits();
+        assert_eq!(
+            grid.ray((2, 2), Direction::Up)
+                .map(|(_, &cell)| cell)
+                .collect::<Vec<_>>(),
+            vec![5, 3]
+        );
+        assert_eq!(
+            grid.ray((0, 0), Direction::DownRight)
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>(),
+            vec![(1, 1), (2, 2)]
+        );
+        assert_eq!(grid.ray((0, 1), Direction::Left).count(), 0);
+    }
+}
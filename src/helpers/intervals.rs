@@ -0,0 +1,280 @@
+/*
+ * Inclusive integer intervals, and sets of them kept merged, for puzzles about ranges
+ * that are too large to handle value by value.
+ *
+ * Intervals may end at the largest value of their type, there is simply nothing after
+ * them then.
+ */
+use std::ops::RangeInclusive;
+
+use super::geom::Scalar;
+
+/// The values from `start` to `end`, both included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Scalar> Interval<T> {
+    /// Panics if `start` is after `end`, there are no empty intervals.
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "intervals can't end before they start");
+        Interval { start, end }
+    }
+
+    /// The number of values in the interval, which overflows if `T` can't count them.
+    pub fn length(&self) -> T {
+        self.end - self.start + T::ONE
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether all values of `other` are in this interval.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether the intervals have at least one value in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.overlaps(other)
+            .then(|| Interval::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+}
+
+impl<T: Scalar> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Interval::new(*range.start(), *range.end())
+    }
+}
+
+/// A set of values stored as the intervals they form, sorted and without overlapping or
+/// touching intervals.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Scalar> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// Adds the values of `interval`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let first = self.intervals.partition_point(|i| {
+            i.end
+                .checked_add(T::ONE)
+                .is_some_and(|next| next < interval.start)
+        });
+        let after = interval.end.checked_add(T::ONE);
+        let last = self
+            .intervals
+            .partition_point(|i| after.is_none_or(|after| i.start <= after));
+        let merged = if first < last {
+            Interval::new(
+                self.intervals[first].start.min(interval.start),
+                self.intervals[last - 1].end.max(interval.end),
+            )
+        } else {
+            interval
+        };
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Removes the values of `interval`, splitting the intervals it cuts through.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        if first == last {
+            return;
+        }
+
+        let (head, tail) = (self.intervals[first], self.intervals[last - 1]);
+        let mut rest = vec![];
+        // Both bounds are strictly inside the cut intervals, so stepping past them can't overflow.
+        if head.start < interval.start {
+            rest.push(Interval::new(head.start, interval.start - T::ONE));
+        }
+        if interval.end < tail.end {
+            rest.push(Interval::new(interval.end + T::ONE, tail.end));
+        }
+        self.intervals.splice(first..last, rest);
+    }
+
+    /// Adds all values of `other`.
+    pub fn merge(&mut self, other: &Self) {
+        for &interval in &other.intervals {
+            self.insert(interval);
+        }
+    }
+
+    /// Removes all values of `other`.
+    pub fn subtract(&mut self, other: &Self) {
+        for &interval in &other.intervals {
+            self.remove(interval);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.intervals.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn length(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |length, interval| length + interval.length())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end < value);
+        self.intervals.get(i).is_some_and(|i| i.contains(value))
+    }
+
+    /// The intervals of the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// The intervals within `bounds` that aren't in the set, in order.
+    pub fn gaps(&self, bounds: Interval<T>) -> Vec<Interval<T>> {
+        let mut gaps = vec![];
+        let mut next = bounds.start;
+        for interval in self.intervals.iter().filter(|i| i.overlaps(&bounds)) {
+            if next < interval.start {
+                gaps.push(Interval::new(next, interval.start - T::ONE));
+            }
+            match interval.end.checked_add(T::ONE) {
+                Some(after) => next = next.max(after),
+                None => return gaps,
+            }
+        }
+        if next <= bounds.end {
+            gaps.push(Interval::new(next, bounds.end));
+        }
+        gaps
+    }
+}
+
+impl<T: Scalar> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn intervals(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter().map(|i| (i.start, i.end)).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::from(2..=6);
+        let b = Interval::new(4, 8);
+        assert_eq!(a.length(), 5);
+        assert!(a.overlaps(&b) && b.overlaps(&a));
+        assert!(!a.overlaps(&Interval::new(7, 9)));
+        assert!(a.contains_interval(&Interval::new(3, 6)));
+        assert!(!a.contains_interval(&b));
+        assert_eq!(a.intersection(&b), Some(Interval::new(4, 6)));
+        assert_eq!(a.intersection(&Interval::new(-3, 1)), None);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut s = set(&[(10, 12), (0, 2), (5, 6)]);
+        assert_eq!(intervals(&s), vec![(0, 2), (5, 6), (10, 12)]);
+        s.insert(Interval::new(3, 3));
+        assert_eq!(intervals(&s), vec![(0, 3), (5, 6), (10, 12)]);
+        s.insert(Interval::new(4, 11));
+        assert_eq!(intervals(&s), vec![(0, 12)]);
+        s.merge(&set(&[(-5, -2), (20, 20)]));
+        assert_eq!(intervals(&s), vec![(-5, -2), (0, 12), (20, 20)]);
+        assert_eq!(s.length(), 18);
+        assert!(s.contains(-2) && s.contains(20));
+        assert!(!s.contains(-1) && !s.contains(13) && !s.contains(21));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut s = set(&[(0, 10), (20, 30)]);
+        s.remove(Interval::new(5, 5));
+        assert_eq!(intervals(&s), vec![(0, 4), (6, 10), (20, 30)]);
+        s.remove(Interval::new(8, 25));
+        assert_eq!(intervals(&s), vec![(0, 4), (6, 7), (26, 30)]);
+        s.subtract(&set(&[(-1, 4), (30, 40)]));
+        assert_eq!(intervals(&s), vec![(6, 7), (26, 29)]);
+        s.remove(Interval::new(11, 20));
+        assert_eq!(s.length(), 6);
+        s.clear();
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_gaps() {
+        let s = set(&[(-4, -1), (2, 3), (6, 9)]);
+        assert_eq!(
+            s.gaps(Interval::new(0, 10)),
+            vec![
+                Interval::new(0, 1),
+                Interval::new(4, 5),
+                Interval::new(10, 10)
+            ]
+        );
+        assert_eq!(s.gaps(Interval::new(6, 8)), vec![]);
+        assert_eq!(
+            set(&[]).gaps(Interval::new(1, 2)),
+            vec![Interval::new(1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_type_bounds() {
+        let mut s = [Interval::new(0, 9), Interval::new(120, i8::MAX)]
+            .into_iter()
+            .collect::<IntervalSet<i8>>();
+        s.insert(Interval::new(110, 119));
+        assert_eq!(s.iter().count(), 2);
+        s.insert(Interval::new(i8::MIN, -1));
+        assert_eq!(
+            s.iter().copied().collect::<Vec<_>>(),
+            vec![Interval::new(i8::MIN, 9), Interval::new(110, i8::MAX)]
+        );
+        assert_eq!(
+            s.gaps(Interval::new(100, i8::MAX)),
+            vec![Interval::new(100, 109)]
+        );
+        s.remove(Interval::new(i8::MAX, i8::MAX));
+        assert!(!s.contains(i8::MAX) && s.contains(i8::MAX - 1));
+        s.insert(Interval::new(10, i8::MAX));
+        assert_eq!(
+            s.iter().copied().collect::<Vec<_>>(),
+            vec![Interval::new(i8::MIN, i8::MAX)]
+        );
+    }
+}
@@ -1,4 +1,11 @@
 /*
  * Use this file if you want to extract helpers from your solutions.
- * Example import from this file: `use advent_of_code::helpers::example_fn;`.
+ * Example import from this file: `use advent_of_code::helpers::grid::Grid;`.
  */
+pub mod geom;
+pub mod grid;
+pub mod intervals;
+pub mod parse;
+pub mod sparse_grid;
+
+pub use sparse_grid::SparseGrid;
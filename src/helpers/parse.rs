@@ -0,0 +1,246 @@
+/*
+ * nom combinators for the shapes puzzle inputs keep coming in. They return `ParseResult`s,
+ * so they mix with the parsers in the day modules and their errors end up in `run_parser`.
+ */
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending, one_of},
+    combinator::{map, map_res, opt, recognize},
+    error::{context, VerboseError, VerboseErrorKind},
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair, tuple},
+    Parser,
+};
+
+use super::geom::{Point2, Point3, Scalar};
+use super::grid::Grid;
+use crate::ParseResult;
+
+fn error<'a, T>(remaining: &'a str, expected: &'static str) -> ParseResult<'a, T> {
+    Err(nom::Err::Error(VerboseError {
+        errors: vec![(remaining, VerboseErrorKind::Context(expected))],
+    }))
+}
+
+/// Runs `parser` and fails unless nothing but whitespace is left after it, where lists
+/// would otherwise stop silently at the first malformed item. The error is the one `parser`
+/// gives on what is left, so it says what is wrong with that item.
+pub fn finish_all<'a, T>(
+    mut parser: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, T> {
+    move |input| {
+        let (remaining, output) = parser.parse(input)?;
+        match remaining.trim_start() {
+            "" => Ok(("", output)),
+            remaining => match parser.parse(remaining) {
+                Err(error) => Err(error),
+                Ok(_) => error(remaining, "end of input"),
+            },
+        }
+    }
+}
+
+/// An `item` on each line.
+pub fn lines<'a, T>(
+    item: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>> {
+    separated_list1(line_ending, item)
+}
+
+/// The end of a line followed by an empty line, which separates the sections of an input.
+pub fn blank_line(input: &str) -> ParseResult<'_, ()> {
+    map(pair(line_ending, line_ending), |_| ())(input)
+}
+
+/// Blocks of lines separated by a blank line, each parsed by `block`.
+pub fn blocks<'a, T>(
+    block: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>> {
+    separated_list1(blank_line, block)
+}
+
+/// A decimal integer with an optional sign, as any type that parses from one.
+pub fn integer<T: FromStr>(input: &str) -> ParseResult<'_, T> {
+    context(
+        "an integer",
+        map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse),
+    )(input)
+}
+
+/// Integers separated by `separator`, e.g. `", "`.
+pub fn integers<'a, T: FromStr>(
+    separator: &'static str,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>> {
+    separated_list1(tag(separator), integer)
+}
+
+/// A `key=value` pair such as `x=-3`, returning the value.
+pub fn key_value<'a, T>(
+    key: &'static str,
+    value: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, T> {
+    preceded(pair(context(key, tag(key)), tag("=")), value)
+}
+
+/// Two integers separated by `separator`, like `3,4`.
+pub fn point2<'a, T: Scalar + FromStr>(
+    separator: &'static str,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Point2<T>> {
+    map(
+        separated_pair(integer, tag(separator), integer),
+        |(x, y)| Point2::new(x, y),
+    )
+}
+
+/// Three integers separated by `separator`, like `1,-2,3`.
+pub fn point3<'a, T: Scalar + FromStr>(
+    separator: &'static str,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Point3<T>> {
+    map(
+        tuple((
+            integer,
+            preceded(tag(separator), integer),
+            preceded(tag(separator), integer),
+        )),
+        |(x, y, z)| Point3::new(x, y, z),
+    )
+}
+
+/// A grid with a line per row, up to a blank line or the end of the input, turning each
+/// character into a cell with `cell`. Like [`Grid::parse`], but for a grid that is only
+/// part of the input. The line ending after the last row is left for the next parser.
+pub fn grid<'a, T>(
+    expected: &'static str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Grid<T>> {
+    move |input| {
+        let mut grid = Grid::default();
+        let mut rest = input;
+        loop {
+            let line = &rest[..rest.find(['\r', '\n']).unwrap_or(rest.len())];
+            let mut row = Vec::with_capacity(grid.width());
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => row.push(value),
+                    None => return error(&rest[i..], expected),
+                }
+            }
+            match row.len() {
+                0 => return error(rest, expected),
+                width if grid.height() > 0 && width < grid.width() => {
+                    return error(&rest[line.len()..], "a row as wide as the first one")
+                }
+                width if grid.height() > 0 && width > grid.width() => {
+                    let column = line.char_indices().nth(grid.width()).unwrap().0;
+                    return error(&rest[column..], "end of the row");
+                }
+                _ => grid.push_row(row),
+            }
+
+            rest = &rest[line.len()..];
+            match line_ending::<_, VerboseError<&str>>(rest) {
+                Ok((next, _)) if !next.is_empty() && !next.starts_with(['\r', '\n']) => rest = next,
+                _ => return Ok((rest, grid)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_parser;
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(
+            run_parser(lines(integers::<i32>(", ")), "1, -2\n+3\n"),
+            Ok(vec![vec![1, -2], vec![3]])
+        );
+        assert_eq!(
+            run_parser(blocks(lines(integer::<u8>)), "1\n2\n\n3\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(
+            run_parser(lines(integer::<u8>), "1\n-2\n")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 2, col 1: expected an integer"
+        );
+        assert_eq!(
+            run_parser(integer::<u8>, "1 2").unwrap_err().to_string(),
+            "parse error at line 1, col 3: expected end of input"
+        );
+        assert_eq!(
+            run_parser(lines(integer::<u8>), "x\n")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 1, col 1: expected an integer"
+        );
+    }
+
+    #[test]
+    fn test_points() {
+        let sensor = preceded(
+            tag("at "),
+            separated_pair(
+                key_value("x", integer::<i64>),
+                tag(", "),
+                key_value("y", integer),
+            ),
+        );
+        assert_eq!(run_parser(sensor, "at x=2, y=-18"), Ok((2, -18)));
+        assert_eq!(
+            run_parser(point3(","), "1,-2,3"),
+            Ok(Point3::<i16>::new(1, -2, 3))
+        );
+        assert_eq!(
+            run_parser(point2::<i32>(" "), "1 x")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 1, col 3: expected an integer"
+        );
+        assert_eq!(
+            run_parser(key_value("y", integer::<u8>), "x=1")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 1, col 1: expected y"
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let digits = |c: char| c.to_digit(10);
+        let (map, moves) = run_parser(
+            separated_pair(grid("a digit", digits), blank_line, integers::<u8>(",")),
+            "123\n456\n\n1,2\n",
+        )
+        .unwrap();
+        assert_eq!(map.row(1), &[4, 5, 6]);
+        assert_eq!(moves, vec![1, 2]);
+
+        let error = |input| {
+            run_parser(grid("a digit", digits), input)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("12\n1x\n"),
+            "parse error at line 2, col 2: expected a digit"
+        );
+        assert_eq!(
+            error("12\n1\n"),
+            "parse error at line 2, col 2: expected a row as wide as the first one"
+        );
+        assert_eq!(
+            error("12\n123\n"),
+            "parse error at line 2, col 3: expected end of the row"
+        );
+        assert_eq!(
+            error("\n12\n"),
+            "parse error at line 1, col 1: expected a digit"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+/*
+ * A grid without fixed bounds, for simulations that spread in any direction.
+ *
+ * Only the cells that were set are stored, keyed by `Point2` coordinates. Like in `Grid`,
+ * `y` grows downwards when rendering.
+ */
+use std::collections::HashMap;
+
+use super::geom::{Point2, Rect};
+
+pub type Coord = Point2<i64>;
+pub type Bounds = Rect<i64>;
+
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+    /// The bounds of `cells`, `None` while there are none.
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid::default()
+    }
+
+    /// Sets the cell at `coord`, returning its previous value.
+    pub fn insert(&mut self, coord: Coord, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.expand(coord),
+            None => Bounds::at(coord),
+        });
+        self.cells.insert(coord, value)
+    }
+
+    /// Clears the cell at `coord`. The bounds shrink if it was the last one on their edge.
+    pub fn remove(&mut self, coord: Coord) -> Option<T> {
+        let value = self.cells.remove(&coord)?;
+        let on_edge = self.bounds.is_some_and(|bounds| {
+            [bounds.min.x, bounds.max.x].contains(&coord.x)
+                || [bounds.min.y, bounds.max.y].contains(&coord.y)
+        });
+        if on_edge {
+            self.bounds = Bounds::enclosing(self.cells.keys().copied());
+        }
+        Some(value)
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.cells.get(&coord)
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.cells.get_mut(&coord)
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.cells.contains_key(&coord)
+    }
+
+    /// The number of cells that are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest bounds that contain every cell that is set.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// The cells that are set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells.iter().map(|(&coord, value)| (coord, value))
+    }
+
+    /// Draws the cells within `viewport` with a line per row, turning each cell into a
+    /// character with `cell`. Cells that aren't set are passed as `None`.
+    pub fn render_viewport(
+        &self,
+        viewport: Bounds,
+        mut cell: impl FnMut(Coord, Option<&T>) -> char,
+    ) -> String {
+        let mut text = String::new();
+        for y in viewport.min.y..=viewport.max.y {
+            for x in viewport.min.x..=viewport.max.x {
+                let coord = Point2::new(x, y);
+                text.push(cell(coord, self.get(coord)));
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Draws all cells that are set, see [`SparseGrid::render_viewport`].
+    pub fn render(&self, cell: impl FnMut(Coord, Option<&T>) -> char) -> String {
+        match self.bounds {
+            Some(bounds) => self.render_viewport(bounds, cell),
+            None => String::new(),
+        }
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (coord, value) in iter {
+            grid.insert(coord, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: i64, y: i64) -> Coord {
+        Point2::new(x, y)
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.render(|_, _| '#'), "");
+
+        grid.insert(p(0, 0), 'a');
+        grid.insert(p(-3, 2), 'b');
+        assert_eq!(grid.insert(p(0, 0), 'c'), Some('a'));
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            bounds,
+            Bounds {
+                min: p(-3, 0),
+                max: p(0, 2)
+            }
+        );
+        assert_eq!((bounds.width(), bounds.height()), (4, 3));
+
+        grid.insert(p(-1, 1), 'd');
+        assert_eq!(grid.remove(p(-3, 2)), Some('b'));
+        assert_eq!(grid.remove(p(-3, 2)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: p(-1, 0),
+                max: p(0, 1)
+            })
+        );
+        assert_eq!(grid.len(), 2);
+        grid.remove(p(0, 0));
+        grid.remove(p(-1, 1));
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = [(p(1, -1), 3), (p(-1, 0), 1), (p(0, 1), 2)]
+            .into_iter()
+            .collect::<SparseGrid<u32>>();
+        let render = |coord: Coord, cell: Option<&u32>| match cell {
+            Some(&value) => char::from_digit(value, 10).unwrap(),
+            None if coord == p(0, 0) => 's',
+            None => '.',
+        };
+        assert_eq!(grid.render(render), "..3\n1s.\n.2.\n");
+        assert_eq!(
+            grid.render_viewport(
+                Bounds {
+                    min: p(0, 0),
+                    max: p(2, 1)
+                },
+                render
+            ),
+            "s..\n2..\n"
+        );
+    }
+}
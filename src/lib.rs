@@ -1,94 +1,459 @@
 /*
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
- * Prefer `./helpers.rs` if you want to extract code from your solutions.
+ * Prefer `./helpers/mod.rs` if you want to extract code from your solutions.
  */
+use std::collections::BTreeMap;
 use std::env;
+use std::ffi::OsString;
+use std::fmt::{self, Display};
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
 
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::{IResult, Parser};
+
+pub mod cache;
+pub mod client;
+pub mod days;
 pub mod helpers;
+pub mod puzzle;
+pub mod runner;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
+/// The answer to one part of a puzzle, independent of the type a day's solver returns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::UInt(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident, $target:ty, $($source:ty),+) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_answer_from!(Int, i64, i8, i16, i32, i64, isize);
+impl_answer_from!(UInt, u64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// An error that keeps a part from being solved, reported by the runner instead of a panic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    Io {
+        path: PathBuf,
+        message: String,
+    },
+    /// The input doesn't have the expected format. Lines and columns start at 1.
+    Parse {
+        line: usize,
+        column: usize,
+        expected: String,
+    },
+    /// The part panicked. The message includes the location of the panic, if known.
+    Panic {
+        message: String,
+    },
+    /// The part didn't finish within the runner's time limit.
+    Timeout {
+        limit: Duration,
+    },
+    /// A puzzle parameter has a value the day can't use.
+    Param {
+        name: String,
+        message: String,
+    },
+}
+
+impl Error {
+    /// Creates a parse error for the position of `remaining`, which must be a suffix of `input`.
+    pub fn parse(input: &str, remaining: &str, expected: impl Into<String>) -> Self {
+        let consumed = &input[..input.len().saturating_sub(remaining.len())];
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+
+        Error::Parse {
+            line: consumed.matches('\n').count() + 1,
+            column: consumed[line_start..].chars().count() + 1,
+            expected: expected.into(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, message } => {
+                write!(f, "could not read {}: {}", path.display(), message)
+            }
+            Error::Parse {
+                line,
+                column,
+                expected,
+            } => write!(
+                f,
+                "parse error at line {}, col {}: expected {}",
+                line, column, expected
+            ),
+            Error::Panic { message } => write!(f, "PANIC {}", message),
+            Error::Timeout { limit } => write!(f, "TIMEOUT after {:.2?}", limit),
+            Error::Param { name, message } => {
+                write!(f, "invalid parameter {}: {}", name, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Result of the nom parsers in the day modules. The verbose error keeps the `context` of a
+/// failure, which ends up as the expected value in [`Error::Parse`], so phrase contexts as
+/// what was expected, e.g. `context("'Monkey'", tag("Monkey"))`.
+pub type ParseResult<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// Runs `parser` on `input`, turning a failure into an [`Error::Parse`] at its position.
+/// Anything but whitespace left over after the parser is done is an error as well, see
+/// [`helpers::parse::finish_all`].
+pub fn run_parser<'a, T>(
+    parser: impl Parser<&'a str, T, VerboseError<&'a str>>,
+    input: &'a str,
+) -> Result<T, Error> {
+    match helpers::parse::finish_all(parser)(input) {
+        Ok((_, output)) => Ok(output),
+        Err(nom::Err::Incomplete(_)) => Err(Error::parse(input, "", "more input")),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            // errors are ordered from the innermost parser outwards. The innermost context
+            // is the most specific description, and otherwise the innermost error is used.
+            let context = error
+                .errors
+                .iter()
+                .find_map(|(remaining, kind)| match kind {
+                    VerboseErrorKind::Context(context) => Some((*remaining, context.to_string())),
+                    _ => None,
+                });
+            let (remaining, expected) = context.unwrap_or_else(|| match error.errors.first() {
+                Some((remaining, VerboseErrorKind::Char(c))) => (*remaining, format!("'{}'", c)),
+                Some((remaining, VerboseErrorKind::Nom(kind))) => {
+                    (*remaining, kind.description().to_lowercase())
                 }
-                None => {
-                    println!("not solved.")
+                _ => (input, "valid input".to_string()),
+            });
+            Err(Error::parse(input, remaining, expected))
+        }
+    }
+}
+
+/// Conversion of the values returned by `part_one` and `part_two` into answers. Parts can
+/// return an `Option`, or a `Result` of one when they can fail on bad input.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Option<Answer>, Error>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Result<Option<Answer>, Error> {
+        Ok(self.map(Into::into))
+    }
+}
+
+impl<T: Into<Answer>> IntoAnswer for Result<Option<T>, Error> {
+    fn into_answer(self) -> Result<Option<Answer>, Error> {
+        self.map(|answer| answer.map(Into::into))
+    }
+}
+
+/// Puzzle parameters by name, as given in the header of an example or with `--param`. Days
+/// read them into the typed parameters they declare with [`params!`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParamValues(BTreeMap<String, String>);
+
+impl ParamValues {
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    /// The value of the parameter `name`, or `default` if it isn't set.
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> Result<T, Error>
+    where
+        T::Err: Display,
+    {
+        match self.0.get(name) {
+            Some(value) => value.parse().map_err(|e: T::Err| Error::Param {
+                name: name.to_string(),
+                message: format!("can't use \"{}\", {}", value, e),
+            }),
+            None => Ok(default),
+        }
+    }
+
+    /// Fails for the first parameter that isn't one of `known`, to catch typos.
+    pub fn check_names(&self, known: &[&str]) -> Result<(), Error> {
+        match self.0.keys().find(|name| !known.contains(&name.as_str())) {
+            Some(name) => Err(Error::Param {
+                name: name.clone(),
+                message: if known.is_empty() {
+                    "the day has no parameters".to_string()
+                } else {
+                    format!("unknown, expected one of {}", known.join(", "))
+                },
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// The typed puzzle parameters of a day. Implemented by [`params!`], and by `()` for days
+/// without parameters.
+pub trait PuzzleParams: Sized + Default {
+    fn from_values(values: &ParamValues) -> Result<Self, Error>;
+}
+
+impl PuzzleParams for () {
+    fn from_values(values: &ParamValues) -> Result<Self, Error> {
+        values.check_names(&[])
+    }
+}
+
+/// Declares the puzzle parameters of a day as a `Params` struct. The defaults are the values
+/// for the real input, which examples and `--param` can override by field name:
+///
+/// ```ignore
+/// crate::params! {
+///     /// The row to count the positions without a beacon in.
+///     row: i32 = 2000000,
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    ($($(#[$meta:meta])* $name:ident: $ty:ty = $default:expr),* $(,)?) => {
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct Params {
+            $($(#[$meta])* pub $name: $ty,)*
+        }
+
+        impl Default for Params {
+            fn default() -> Self {
+                Params {
+                    $($name: $default,)*
                 }
             }
         }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        impl $crate::PuzzleParams for Params {
+            fn from_values(values: &$crate::ParamValues) -> Result<Self, $crate::Error> {
+                values.check_names(&[$(stringify!($name)),*])?;
+                let defaults = Params::default();
+                Ok(Params {
+                    $($name: values.get(stringify!($name), defaults.$name)?,)*
+                })
+            }
+        }
+    };
+}
+
+/// A day's solution, callable in-process by the runner.
+///
+/// Day modules don't implement this by hand, they call [`solution!`] instead.
+pub trait Solution: Sync {
+    fn part_one(&self, input: &str, params: &ParamValues) -> Result<Option<Answer>, Error>;
+    fn part_two(&self, input: &str, params: &ParamValues) -> Result<Option<Answer>, Error>;
+
+    fn solve(&self, part: u8, input: &str, params: &ParamValues) -> Result<Option<Answer>, Error> {
+        match part {
+            1 => self.part_one(input, params),
+            2 => self.part_two(input, params),
+            _ => panic!("puzzles only have two parts, got part {}", part),
+        }
+    }
+
+    /// Whether the day has a separate parsing step that [`Solution::parse`] can time.
+    fn has_parser(&self) -> bool {
+        false
+    }
+
+    /// Runs only the day's parsing step and discards the result.
+    fn parse(&self, _input: &str) {}
+}
+
+/// Declares a `Solver` that implements [`Solution`] for the `part_one` and `part_two`
+/// functions of the calling module. Register it in the `mod.rs` of its year to make it runnable.
+///
+/// Pass the name of the module's parsing function, e.g. `solution!(parse)`, so benchmarks
+/// can time parsing separately from solving. Days with puzzle parameters declared by
+/// [`params!`] pass them as well, `solution!(parse, Params)`, and take them as the second
+/// argument of their parts: `fn part_one(input: &str, params: &Params)`.
+///
+/// Also generates a `test_examples` test that checks the answers of the day's examples, see
+/// [`runner::examples`].
+#[macro_export]
+macro_rules! solution {
+    () => {
+        $crate::solution!(@solver []);
+    };
+    ($parse:ident) => {
+        $crate::solution!(@solver [] $crate::solution!(@parser $parse););
+    };
+    ($parse:ident, $params:ty) => {
+        $crate::solution!(@solver [$params] $crate::solution!(@parser $parse););
+    };
+    (@parser $parse:ident) => {
+        fn has_parser(&self) -> bool {
+            true
+        }
+
+        fn parse(&self, input: &str) {
+            let _ = std::hint::black_box($parse(input));
+        }
+    };
+    (@call [] $part:ident, $input:ident, $values:ident) => {{
+        <() as $crate::PuzzleParams>::from_values($values)?;
+        $crate::IntoAnswer::into_answer($part($input))
+    }};
+    (@call [$params:ty] $part:ident, $input:ident, $values:ident) => {{
+        let params = <$params as $crate::PuzzleParams>::from_values($values)?;
+        $crate::IntoAnswer::into_answer($part($input, &params))
     }};
+    (@solver [$($params:ty)?] $($parser:tt)*) => {
+        pub struct Solver;
+
+        impl $crate::Solution for Solver {
+            fn part_one(
+                &self,
+                input: &str,
+                values: &$crate::ParamValues,
+            ) -> Result<Option<$crate::Answer>, $crate::Error> {
+                $crate::solution!(@call [$($params)?] part_one, input, values)
+            }
+
+            fn part_two(
+                &self,
+                input: &str,
+                values: &$crate::ParamValues,
+            ) -> Result<Option<$crate::Answer>, $crate::Error> {
+                $crate::solution!(@call [$($params)?] part_two, input, values)
+            }
+
+            $($parser)*
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn test_examples() {
+            $crate::runner::assert_examples(module_path!(), &Solver);
+        }
+    };
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Environment variable pointing to a directory to read puzzle inputs from instead of
+/// `src/inputs`, with the same layout of a directory per year.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Whether `AOC_INPUT_DIR` points the inputs somewhere else than `src/inputs`.
+pub fn has_custom_input_dir() -> bool {
+    env::var_os(INPUT_DIR_VAR).is_some_and(|dir| !dir.is_empty())
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// The directory of `folder`. Relative to the crate rather than the working directory, so
+/// the binaries can be run from anywhere.
+fn folder_dir(folder: &str, input_dir: Option<OsString>) -> PathBuf {
+    match input_dir {
+        Some(dir) if folder == "inputs" && !dir.is_empty() => PathBuf::from(dir),
+        _ => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src")
+            .join(folder),
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// The file of `day` in `folder`, e.g. `src/inputs/2022/07.txt`.
+pub fn input_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    folder_dir(folder, env::var_os(INPUT_DIR_VAR))
+        .join(year.to_string())
+        .join(format!("{:02}.txt", day))
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
+/// Reads the input at `path`, or stdin if `path` is `-`.
+pub fn read_input(path: &Path) -> Result<String, Error> {
+    let result = if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        fs::read_to_string(path)
+    };
+    result.map_err(|e| Error::Io {
+        path: if path == Path::new("-") {
+            PathBuf::from("stdin")
         } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
+            path.to_path_buf()
+        },
+        message: e.to_string(),
     })
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> Result<String, Error> {
+    read_input(&input_path(folder, year, day))
+}
+
+/// Writes `contents` to a temporary file next to `path` and renames it into place, so readers
+/// never see a partial file and concurrent writers don't clobber each other's temporary files.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(dir)?;
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp_path = dir.join(format!(".{}.{}.tmp", file_name, std::process::id()));
+    fs::write(&tmp_path, contents)
+        .and_then(|_| fs::rename(&tmp_path, path))
+        .inspect_err(|_| {
+            let _ = fs::remove_file(&tmp_path);
+        })
+}
+
+/// Like [`try_read_file`], but panics if the file can't be read. Meant for tests.
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    try_read_file(folder, year, day).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Loads the example `name` of `day`, panicking if it can't be read. Meant for tests.
+pub fn read_example(year: u16, day: u8, name: &str) -> runner::Example {
+    runner::load_example(year, day, name).unwrap_or_else(|e| panic!("{}", e))
 }
 
 #[cfg(test)]
@@ -96,30 +461,147 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_input_path() {
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(folder_dir("inputs", None), crate_dir.join("src/inputs"));
+        assert_eq!(
+            folder_dir("inputs", Some("/tmp/aoc".into())),
+            PathBuf::from("/tmp/aoc")
+        );
+        assert_eq!(
+            folder_dir("inputs", Some("".into())),
+            crate_dir.join("src/inputs")
+        );
+        assert_eq!(
+            folder_dir("examples", Some("/tmp/aoc".into())),
+            crate_dir.join("src/examples")
         );
+    }
+
+    #[test]
+    fn test_read_input() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/examples/2022/01/example.txt");
+        assert!(read_input(&path).unwrap().starts_with("1: 24000\n"));
+
+        let path = PathBuf::from("no/such/input.txt");
+        match read_input(&path) {
+            Err(Error::Io {
+                path: error_path, ..
+            }) => assert_eq!(error_path, path),
+            other => panic!("expected an io error, got {:?}", other),
+        }
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+    #[test]
+    fn test_write_atomic() {
+        let dir = env::temp_dir().join(format!("aoc-write-atomic-{}", std::process::id()));
+        let path = dir.join("inputs/01.txt");
+        write_atomic(&path, "old").unwrap();
+        write_atomic(&path, "new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_param_values() {
+        use days::y2022::day15::Params;
+
+        let mut values = ParamValues::default();
+        assert_eq!(Params::from_values(&values), Ok(Params::default()));
+        assert_eq!(<()>::from_values(&values), Ok(()));
+
+        values.insert("row", "10");
+        assert_eq!(
+            Params::from_values(&values),
+            Ok(Params {
+                row: 10,
+                ..Params::default()
+            })
+        );
+        assert_eq!(
+            <()>::from_values(&values).unwrap_err().to_string(),
+            "invalid parameter row: the day has no parameters"
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+        values.insert("row", "ten");
+        assert_eq!(
+            Params::from_values(&values).unwrap_err().to_string(),
+            "invalid parameter row: can't use \"ten\", invalid digit found in string"
         );
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
+        values.insert("rows", "10");
+        assert_eq!(
+            Params::from_values(&values).unwrap_err().to_string(),
+            "invalid parameter rows: unknown, expected one of row, max_coord"
+        );
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(24000_u32).to_string(), "24000");
+        assert_eq!(Answer::from(-3_i32).to_string(), "-3");
+        assert_eq!(Answer::from(13954061248_u64).to_string(), "13954061248");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let input = "Monkey 0:\n  Test: divisible by 23\n";
+        let remaining = &input[12..];
+        assert_eq!(
+            Error::parse(input, remaining, "'If true'").to_string(),
+            "parse error at line 2, col 3: expected 'If true'"
+        );
+        assert_eq!(
+            Error::parse(input, input, "'Monkey'"),
+            Error::Parse {
+                line: 1,
+                column: 1,
+                expected: "'Monkey'".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_run_parser() {
+        use nom::bytes::complete::tag;
+        use nom::character::complete::{newline, u32};
+        use nom::error::context;
+        use nom::sequence::{preceded, terminated};
+
+        let mut parser = terminated(
+            preceded(
+                context("'Test: divisible by'", tag("Test: divisible by ")),
+                u32,
             ),
-            10400.50_f64
+            newline,
+        );
+        assert_eq!(run_parser(&mut parser, "Test: divisible by 23\n"), Ok(23));
+        assert_eq!(
+            run_parser(&mut parser, "Test: divisible 23\n")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 1, col 1: expected 'Test: divisible by'"
+        );
+        assert_eq!(
+            run_parser(&mut parser, "Test: divisible by x\n")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 1, col 20: expected digit"
+        );
+        assert_eq!(
+            run_parser(&mut parser, "Test: divisible by 23\n\n 7")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 3, col 2: expected 'Test: divisible by'"
+        );
+        assert_eq!(
+            run_parser(&mut parser, "Test: divisible by 23\nTest: divisible by 5\n")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 2, col 1: expected end of input"
         );
     }
 }
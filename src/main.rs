@@ -2,45 +2,263 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use std::fs;
+use std::io;
+use std::process;
+use std::time::Duration;
+
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+use advent_of_code::runner::{
+    append_history, compare, current_commit, entries_for_run, execute_day, exit_on_failure,
+    exit_with_usage_error, find_baseline, finish_args, format_comparison, format_summary,
+    load_history, print_part, print_structured, warn_about_input, write_readme, CheckSummary,
+    DayFilter, Format, HistoryOptions, Options, PartResult,
+};
+use advent_of_code::{days, input_path, Error, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// What happened to one of the selected days.
+enum DayRun {
+    /// Left out for being marked as slow.
+    Skipped,
+    /// There's no registered solution or no input file for the day.
+    NotSolved(Vec<PartResult>),
+    Solved(Vec<PartResult>),
+}
+
+fn run_day(options: &Options, filter: &DayFilter, year: u16, day: u8) -> DayRun {
+    if filter.is_skipped(year, day) {
+        return DayRun::Skipped;
+    }
+
+    let path = input_path("inputs", year, day);
+    match (days::get(year, day), fs::read_to_string(&path)) {
+        (Some(day), Ok(input)) => {
+            warn_about_input(day.year, day.day, &input);
+            DayRun::Solved(execute_day(options, day, &input))
+        }
+        // an input that exists but can't be read is reported like `cargo solve` does.
+        (Some(_), Err(e)) if e.kind() != io::ErrorKind::NotFound => DayRun::Solved(
+            options
+                .parts()
+                .into_iter()
+                .map(|part| {
+                    let error = Error::Io {
+                        path: path.clone(),
+                        message: e.to_string(),
+                    };
+                    PartResult::from_outcome(year, day, part, Err(error), Duration::ZERO)
+                })
+                .collect(),
+        ),
+        _ => DayRun::NotSolved(
+            options
+                .parts()
+                .into_iter()
+                .map(|part| PartResult::unsolved(year, day, part))
+                .collect(),
+        ),
+    }
+}
+
+impl DayRun {
+    fn results_mut(&mut self) -> &mut [PartResult] {
+        match self {
+            DayRun::Skipped => &mut [],
+            DayRun::NotSolved(results) | DayRun::Solved(results) => results,
+        }
+    }
+}
+
+fn print_day(year: u16, day: u8, run: &DayRun) {
+    println!("---------------");
+    println!("{}| {} Day {:02} |{}", ANSI_BOLD, year, day, ANSI_RESET);
+    println!("---------------");
+
+    match run {
+        DayRun::Skipped => println!("Skipped, marked as slow."),
+        DayRun::NotSolved(_) => println!("Not solved."),
+        DayRun::Solved(results) => results.iter().for_each(print_part),
+    }
+}
+
+fn parse_jobs(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(0) => Err("need at least one job".to_string()),
+        Ok(jobs) => Ok(jobs),
+        Err(e) => Err(e.to_string()),
+    }
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+    let mut args = pico_args::Arguments::from_env();
+    let options = Options::parse(&mut args).unwrap_or_else(|e| exit_with_usage_error(e));
+    let mut history_options =
+        HistoryOptions::parse(&mut args).unwrap_or_else(|e| exit_with_usage_error(e));
+    let jobs = args
+        .opt_value_from_fn("--jobs", parse_jobs)
+        .unwrap_or_else(|e| exit_with_usage_error(e))
+        .filter(|&jobs| jobs > 1);
+    let update_readme = args.contains("--write-readme");
+    let params: Vec<String> = args
+        .values_from_str("--param")
+        .unwrap_or_else(|e| exit_with_usage_error(e));
+    if !params.is_empty() {
+        eprintln!("--param is specific to a day, pass it to `cargo solve [<year>] <day>` instead.");
+        process::exit(1);
+    }
+    let filter = DayFilter::parse(&mut args).unwrap_or_else(|e| exit_with_usage_error(e));
+    finish_args(args);
+
+    let is_text = options.format == Format::Text;
+    let selected = filter
+        .years()
+        .into_iter()
+        .flat_map(|year| (1..=25).map(move |day| (year, day)))
+        .filter(|&(_, day)| filter.is_selected(day))
+        .collect::<Vec<_>>();
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let runs: Vec<DayRun> = match jobs {
+        Some(jobs) => {
+            if is_text {
+                println!(
+                    "{}Running up to {} days at once, so timings aren't isolated.{}",
+                    ANSI_ITALIC, jobs, ANSI_RESET
+                );
             }
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(jobs)
+                .build()
+                .expect("could not start the thread pool");
+            // results are collected in year and day order and printed once all days are done.
+            let mut runs = pool.install(|| {
+                selected
+                    .par_iter()
+                    .map(|&(year, day)| run_day(&options, &filter, year, day))
+                    .collect::<Vec<_>>()
+            });
+            for result in runs.iter_mut().flat_map(DayRun::results_mut) {
+                result.concurrent = true;
+            }
+            if is_text {
+                selected
+                    .iter()
+                    .zip(&runs)
+                    .for_each(|(&(year, day), run)| print_day(year, day, run));
+            }
+            runs
+        }
+        None => selected
+            .iter()
+            .map(|&(year, day)| {
+                let run = run_day(&options, &filter, year, day);
+                if is_text {
+                    print_day(year, day, &run);
+                }
+                run
+            })
+            .collect(),
+    };
+
+    let results: Vec<PartResult> = runs
+        .into_iter()
+        .flat_map(|run| match run {
+            DayRun::Skipped => vec![],
+            DayRun::NotSolved(results) | DayRun::Solved(results) => results,
         })
-        .sum();
+        .collect();
+
+    // timings of days running side by side would skew the history.
+    if jobs.is_some() {
+        history_options.record = false;
+    }
+    let comparison = record_timings(&history_options, &results);
+
+    if update_readme {
+        if cfg!(debug_assertions) {
+            eprintln!(
+                "Writing timings of a debug build to the readme, pass --release for real ones."
+            );
+        }
+        match write_readme(&results) {
+            Ok(()) if is_text => println!("Updated the benchmarks table in README.md."),
+            Ok(()) => {}
+            Err(e) => eprintln!("Failed to update README.md: {}", e),
+        }
+    }
+
+    if !is_text {
+        print_structured(options.format, &results);
+        if let Some(comparison) = comparison {
+            eprint!("{}", comparison);
+        }
+        exit_on_failure(&results);
+        return;
+    }
+
+    println!("----------");
+    print!("{}", format_summary(&results));
+    println!();
 
+    let total: Duration = results.iter().map(|result| result.elapsed).sum();
     println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        "{}Total{}{}:{} {}{:.2}ms{}",
+        ANSI_BOLD,
+        if options.bench.is_some() {
+            " (sum of medians)"
+        } else {
+            ""
+        },
+        if jobs.is_some() {
+            " (not isolated)"
+        } else {
+            ""
+        },
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total.as_secs_f64() * 1000_f64,
+        ANSI_RESET
     );
+
+    if let Some(comparison) = comparison {
+        print!("{}", comparison);
+    }
+
+    if options.check {
+        let summary = CheckSummary::from_results(&results);
+        println!(
+            "{}Check:{} {} passed, {} failed, {} unknown",
+            ANSI_BOLD, ANSI_RESET, summary.passed, summary.failed, summary.unknown
+        );
+    }
+
+    exit_on_failure(&results);
+}
+
+/// Appends the timings of this run to the history and compares them with the baseline, if
+/// requested. Returns the comparison report.
+fn record_timings(options: &HistoryOptions, results: &[PartResult]) -> Option<String> {
+    let commit = current_commit();
+
+    let report = options.compare.then(|| {
+        let history = load_history();
+        let baseline = find_baseline(&history, &commit, options.baseline.as_deref());
+        match baseline.first() {
+            Some(entry) => format_comparison(
+                &entry.commit,
+                &compare(&baseline, results),
+                options.threshold,
+            ),
+            None => "No baseline timings to compare with.\n".to_string(),
+        }
+    });
+
+    if options.record {
+        if let Err(e) = append_history(&entries_for_run(&commit, results)) {
+            eprintln!("Failed to record timings: {}", e);
+        }
+    }
+
+    report
 }
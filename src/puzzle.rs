@@ -0,0 +1,163 @@
+/*
+ * This file contains template code.
+ * Pulls examples out of the HTML of a puzzle description.
+ *
+ * A description has an `<article>` per part. Examples are `<pre><code>` blocks, and the
+ * answers for them are usually the last `<code><em>` of each part.
+ */
+use std::collections::HashMap;
+
+pub struct CodeBlock {
+    /// The part whose description the block is in.
+    pub part: u8,
+    pub text: String,
+    /// Text of the description between the previous block, or the start of the part, and
+    /// this one.
+    pub context: String,
+}
+
+/// The contents of the `<article>` elements of `html`, without their tags.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some((_, after)) = rest.split_once("<article") {
+        let Some((_, content)) = after.split_once('>') else {
+            break;
+        };
+        let Some((article, after)) = content.split_once("</article>") else {
+            break;
+        };
+        articles.push(article);
+        rest = after;
+    }
+    articles
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The text of `html` without its tags and with entities decoded. Whitespace is kept.
+pub fn html_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(&text)
+}
+
+/// Text between each occurrence of `start` and the following `end` in `html`.
+fn between<'a>(html: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    html.split(start)
+        .skip(1)
+        .filter_map(move |rest| rest.split_once(end).map(|(inner, _)| inner))
+}
+
+/// All `<pre><code>` blocks of `html`, in order. A page without articles is taken as the
+/// description of the first part.
+pub fn code_blocks(html: &str) -> Vec<CodeBlock> {
+    let articles = match articles(html) {
+        articles if articles.is_empty() => vec![html],
+        articles => articles,
+    };
+
+    let mut blocks = vec![];
+    for (part, article) in (1..).zip(articles) {
+        let mut rest = article;
+        while let Some((before, after)) = rest.split_once("<pre><code>") {
+            let Some((code, after)) = after.split_once("</code></pre>") else {
+                break;
+            };
+            blocks.push(CodeBlock {
+                part,
+                text: html_text(code),
+                context: html_text(before),
+            });
+            rest = after;
+        }
+    }
+    blocks
+}
+
+/// Index of the block most likely to be the example input: the first one of part one that
+/// follows a "For example", or else its first one.
+pub fn pick_example(blocks: &[CodeBlock]) -> Option<usize> {
+    blocks
+        .iter()
+        .position(|block| block.part == 1 && block.context.to_lowercase().contains("for example"))
+        .or_else(|| blocks.iter().position(|block| block.part == 1))
+}
+
+/// The answer for the example of each part, taken to be the last emphasized code in its
+/// description.
+pub fn example_answers(html: &str) -> HashMap<u8, String> {
+    (1..)
+        .zip(articles(html))
+        .filter_map(|(part, article)| {
+            let answer = between(article, "<code><em>", "</em></code>").last()?;
+            Some((part, html_text(answer).trim().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = "\
+<main>
+<article class=\"day-desc\"><h2>--- Day 1: Calorie Counting ---</h2>
+<p>Each Elf separates their inventory like <code>1000</code> this:</p>
+<pre><code>a &lt;- b</code></pre>
+<p>For example, suppose the Elves finish writing their items' Calories into a list:</p>
+<pre><code>1000
+2000
+
+<em>3000</em>
+</code></pre>
+<p>This Elf is carrying <code><em>6000</em></code> Calories, the most is <code><em>24000</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>71924</code>.</p>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>
+<p>The sum of the top three is <code><em>45000</em></code>.</p>
+</article>
+</main>";
+
+    #[test]
+    fn test_code_blocks() {
+        let blocks = code_blocks(PAGE);
+        assert_eq!(
+            blocks
+                .iter()
+                .map(|block| (block.part, block.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, "a <- b"), (1, "1000\n2000\n\n3000\n")]
+        );
+        assert!(blocks[1].context.contains("For example, suppose"));
+        assert_eq!(pick_example(&blocks), Some(1));
+
+        let blocks = code_blocks("<pre><code>1\n2\n</code></pre>");
+        assert_eq!(blocks[0].part, 1);
+        assert_eq!(pick_example(&blocks), Some(0));
+        assert_eq!(pick_example(&[]), None);
+    }
+
+    #[test]
+    fn test_example_answers() {
+        let answers = example_answers(PAGE);
+        assert_eq!(answers.get(&1), Some(&"24000".to_string()));
+        assert_eq!(answers.get(&2), Some(&"45000".to_string()));
+        assert_eq!(articles(PAGE).len(), 2);
+    }
+}
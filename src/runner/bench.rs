@@ -0,0 +1,215 @@
+/*
+ * Repeated timing of solutions for `--bench`.
+ */
+use std::hint::black_box;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::{run_isolated, run_part, Options, PartResult};
+use crate::days::Day;
+
+/// Upper bound on samples per measurement, so sub-microsecond parts don't fill memory
+/// before the time budget is used up.
+const MAX_SAMPLES: usize = 10_000;
+
+#[derive(Clone, Copy, Debug)]
+pub struct BenchOptions {
+    /// Exact number of timed runs. When unset, runs are repeated until `budget` is used.
+    pub runs: Option<usize>,
+    pub budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            runs: None,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        // for odd counts, both indices point at the middle sample.
+        let median = (sorted[(runs - 1) / 2] + sorted[runs / 2]) / 2;
+
+        let nanos = sorted
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Stats {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Times repeated calls of `f` as configured by `options`. `warmup` is the duration of a
+/// call made beforehand, which also warmed up caches.
+///
+/// When no run count is given and the warm-up call alone exceeds the budget, it is used as
+/// the only sample instead of running a slow solution again.
+pub fn sample<T>(
+    options: &BenchOptions,
+    warmup: Duration,
+    mut f: impl FnMut() -> T,
+) -> Vec<Duration> {
+    if options.runs.is_none() && warmup >= options.budget {
+        return vec![warmup];
+    }
+
+    let started = Instant::now();
+    let mut samples = vec![];
+    loop {
+        let timer = Instant::now();
+        black_box(f());
+        samples.push(timer.elapsed());
+
+        let done = match options.runs {
+            Some(runs) => samples.len() >= runs,
+            None => started.elapsed() >= options.budget || samples.len() >= MAX_SAMPLES,
+        };
+        if done {
+            break;
+        }
+    }
+
+    samples
+}
+
+/// Benchmarks the selected parts of `day` and its parsing step, if it has one. The first
+/// run of each part is isolated like in [`run_part`], and only parts that returned an
+/// answer are timed again.
+///
+/// The timed runs of a part happen on a single isolated thread, so a panic that only shows
+/// up in a later run is reported for that part instead of ending the benchmark. They aren't
+/// given the timeout, as the warm-up already showed a run fits within it.
+pub fn bench_day(
+    day: &'static Day,
+    input: &Arc<str>,
+    bench: &BenchOptions,
+    options: &Options,
+) -> Vec<PartResult> {
+    let mut results = options
+        .parts()
+        .into_iter()
+        .map(|part| {
+            let warmup = run_part(day, part, input, options);
+            if warmup.error.is_some() || warmup.answer.is_none() {
+                return warmup;
+            }
+
+            let (bench, input, params) = (*bench, Arc::clone(input), options.params.clone());
+            let elapsed = warmup.elapsed;
+            let samples = run_isolated(None, move || {
+                sample(&bench, elapsed, || {
+                    day.solution.solve(part, &input, &params)
+                })
+            });
+            match samples {
+                Ok(samples) => {
+                    let stats = Stats::from_samples(&samples);
+                    PartResult {
+                        elapsed: stats.median,
+                        stats: Some(stats),
+                        ..warmup
+                    }
+                }
+                Err(error) => PartResult {
+                    error: Some(error),
+                    ..warmup
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // parsing would fail the same way as the parts that failed, and timing it is no use for
+    // parts that aren't solved yet.
+    let parts_returned = results.iter().all(|result| result.stats.is_some());
+    if day.solution.has_parser() && parts_returned {
+        let (bench, input) = (*bench, Arc::clone(input));
+        let samples = run_isolated(None, move || {
+            let timer = Instant::now();
+            day.solution.parse(&input);
+            sample(&bench, timer.elapsed(), || day.solution.parse(&input))
+        });
+        if let Ok(samples) = samples {
+            let parse_stats = Stats::from_samples(&samples);
+            for result in &mut results {
+                result.parse_stats = Some(parse_stats);
+            }
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_micros(v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&micros(&[4, 2, 9, 5, 4, 7, 5, 4]));
+        assert_eq!(stats.runs, 8);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(
+            stats.median,
+            Duration::from_micros(4) + Duration::from_nanos(500)
+        );
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.stddev, Duration::from_micros(2));
+
+        let stats = Stats::from_samples(&micros(&[3]));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_sample_runs() {
+        let mut calls = 0;
+        let options = BenchOptions {
+            runs: Some(5),
+            budget: Duration::ZERO,
+        };
+        let samples = sample(&options, Duration::from_secs(1), || calls += 1);
+
+        assert_eq!(samples.len(), 5);
+        assert_eq!(calls, 5);
+    }
+
+    #[test]
+    fn test_sample_slow_warmup() {
+        let options = BenchOptions {
+            runs: None,
+            budget: Duration::from_millis(5),
+        };
+        let samples = sample(&options, Duration::from_millis(8), || ());
+        assert_eq!(samples, vec![Duration::from_millis(8)]);
+    }
+}
@@ -0,0 +1,223 @@
+/*
+ * Verification of computed answers against the store in `src/answers/<year>/<day>.txt`, used
+ * by `--check`.
+ *
+ * Answer files contain one `<part>: <answer>` line per known part. Multi-line answers leave
+ * the value after the colon empty and continue on the following lines, e.g.:
+ *
+ * 1: 14360
+ * 2:
+ * ###...##..
+ * #..#.#..#.
+ */
+use std::collections::HashMap;
+use std::fs;
+
+use super::PartResult;
+use crate::{input_path, Answer};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There's no stored answer for the part yet.
+    Unknown,
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// Splits `text` into `<name>: <value>` fields. A field with an empty value continues on the
+/// following lines, up to the next field. Empty values are kept.
+pub fn parse_fields(text: &str) -> Vec<(&str, String)> {
+    let mut fields = vec![];
+    let mut multiline: Option<(&str, Vec<&str>)> = None;
+
+    for line in text.lines() {
+        let header = line.split_once(':').filter(|(name, _)| {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        });
+
+        match header {
+            Some((name, value)) => {
+                if let Some((name, lines)) = multiline.take() {
+                    fields.push((name, lines.join("\n").trim_end().to_string()));
+                }
+                if value.trim().is_empty() {
+                    multiline = Some((name, vec![]));
+                } else {
+                    fields.push((name, value.trim().to_string()));
+                }
+            }
+            None => {
+                if let Some((_, lines)) = &mut multiline {
+                    lines.push(line);
+                }
+            }
+        }
+    }
+
+    if let Some((name, lines)) = multiline {
+        fields.push((name, lines.join("\n").trim_end().to_string()));
+    }
+    fields
+}
+
+/// Picks the answers out of parsed fields, as a map of part number to answer.
+pub fn answers_from_fields(fields: &[(&str, String)]) -> HashMap<u8, String> {
+    fields
+        .iter()
+        .filter(|(_, answer)| !answer.is_empty())
+        .filter_map(|(name, answer)| Some((name.parse::<u8>().ok()?, answer.clone())))
+        .collect()
+}
+
+/// Parses the contents of an answers file into a map of part number to answer.
+pub fn parse_answers(text: &str) -> HashMap<u8, String> {
+    answers_from_fields(&parse_fields(text))
+}
+
+/// Loads the stored answers for `day`. Days without an answers file have no known answers.
+pub fn load_answers(year: u16, day: u8) -> HashMap<u8, String> {
+    match fs::read_to_string(input_path("answers", year, day)) {
+        Ok(text) => parse_answers(&text),
+        Err(_) => HashMap::new(),
+    }
+}
+
+pub fn verdict(answer: Option<&Answer>, expected: Option<&String>) -> Verdict {
+    match (answer, expected) {
+        (_, None) => Verdict::Unknown,
+        (Some(answer), Some(expected)) if answer.to_string().trim_end() == expected => {
+            Verdict::Pass
+        }
+        (_, Some(expected)) => Verdict::Fail {
+            expected: expected.clone(),
+        },
+    }
+}
+
+/// Compares the results of a single day run with its stored answers.
+pub fn check_day(results: &mut [PartResult]) {
+    let Some((year, day)) = results.first().map(|result| (result.year, result.day)) else {
+        return;
+    };
+
+    let answers = load_answers(year, day);
+    for result in results {
+        result.verdict = Some(verdict(result.answer.as_ref(), answers.get(&result.part)));
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CheckSummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub unknown: usize,
+}
+
+impl CheckSummary {
+    pub fn from_results(results: &[PartResult]) -> Self {
+        let mut summary = CheckSummary::default();
+        for verdict in results.iter().filter_map(|result| result.verdict.as_ref()) {
+            match verdict {
+                Verdict::Pass => summary.passed += 1,
+                Verdict::Fail { .. } => summary.failed += 1,
+                Verdict::Unknown => summary.unknown += 1,
+            }
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::{read_file, ParamValues};
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("1: 14360\n2:\n###..\n#..#.\n\n");
+        assert_eq!(answers.get(&1), Some(&"14360".to_string()));
+        assert_eq!(answers.get(&2), Some(&"###..\n#..#.".to_string()));
+
+        let answers = parse_answers("2: LJSVLTWQM\n");
+        assert_eq!(answers.get(&1), None);
+        assert_eq!(answers.get(&2), Some(&"LJSVLTWQM".to_string()));
+
+        assert!(parse_answers("1:\n2:\n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        assert_eq!(
+            parse_fields("row: 10\n2:\n##..\n#..#\nmax_coord: 20\n"),
+            vec![
+                ("row", "10".to_string()),
+                ("2", "##..\n#..#".to_string()),
+                ("max_coord", "20".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verdict() {
+        let expected = "24000".to_string();
+        assert_eq!(
+            verdict(Some(&Answer::from(24000_u32)), Some(&expected)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            verdict(Some(&Answer::from(24001_u32)), Some(&expected)),
+            Verdict::Fail {
+                expected: expected.clone()
+            }
+        );
+        assert_eq!(
+            verdict(None, Some(&expected)),
+            Verdict::Fail {
+                expected: expected.clone()
+            }
+        );
+        assert_eq!(
+            verdict(Some(&Answer::from(24000_u32)), None),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn test_solutions() {
+        for day in days::all() {
+            let input = read_file("inputs", day.year, day.day);
+            let answers = load_answers(day.year, day.day);
+            for part in 1..=2 {
+                let answer = day
+                    .solution
+                    .solve(part, &input, &ParamValues::default())
+                    .unwrap();
+                assert_eq!(
+                    verdict(answer.as_ref(), answers.get(&part)),
+                    Verdict::Pass,
+                    "{} day {} part {}",
+                    day.year,
+                    day.day,
+                    part
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+/*
+ * Named example inputs in `src/examples/<year>/<day>/<name>.txt`, with their expected answers.
+ *
+ * An example starts with a header of `<name>: <value>` fields, in the format of the answer
+ * files, that ends with a `---` line. Fields named after a part hold its expected answer,
+ * all other fields are puzzle parameters, e.g.:
+ *
+ * 1: 26
+ * 2: 56000011
+ * row: 10
+ * ---
+ * Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+ *
+ * Files without a `---` line are all input, with nothing to check.
+ */
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::check::{answers_from_fields, parse_fields, verdict, Verdict};
+use crate::{input_path, Error, ParamValues, Solution};
+
+const HEADER_END: &str = "---";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub params: ParamValues,
+    /// Expected answers by part.
+    pub answers: HashMap<u8, String>,
+}
+
+pub fn parse_example(name: &str, text: &str) -> Example {
+    let mut example = Example {
+        name: name.to_string(),
+        ..Example::default()
+    };
+
+    let header_end = text.match_indices(HEADER_END).map(|(i, _)| i).find(|&i| {
+        (i == 0 || text[..i].ends_with('\n'))
+            && matches!(
+                text[i + HEADER_END.len()..].chars().next(),
+                None | Some('\n' | '\r')
+            )
+    });
+    let Some(header_end) = header_end else {
+        example.input = text.to_string();
+        return example;
+    };
+
+    let fields = parse_fields(&text[..header_end]);
+    example.answers = answers_from_fields(&fields);
+    for (name, value) in fields {
+        if name.parse::<u8>().is_err() {
+            example.params.insert(name, value);
+        }
+    }
+
+    let input = &text[header_end + HEADER_END.len()..];
+    example.input = input
+        .strip_prefix("\r\n")
+        .or_else(|| input.strip_prefix('\n'))
+        .unwrap_or(input)
+        .to_string();
+    example
+}
+
+fn examples_dir(year: u16, day: u8) -> PathBuf {
+    input_path("examples", year, day).with_extension("")
+}
+
+pub fn example_path(year: u16, day: u8, name: &str) -> PathBuf {
+    examples_dir(year, day).join(format!("{}.txt", name))
+}
+
+pub fn load_example(year: u16, day: u8, name: &str) -> Result<Example, Error> {
+    let path = example_path(year, day, name);
+    let text = fs::read_to_string(&path).map_err(|e| Error::Io {
+        path,
+        message: e.to_string(),
+    })?;
+    Ok(parse_example(name, &text))
+}
+
+/// Loads all examples of `day`, ordered by name. Days without an examples directory have none.
+pub fn load_examples(year: u16, day: u8) -> Result<Vec<Example>, Error> {
+    let dir = examples_dir(year, day);
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Ok(vec![]);
+    };
+
+    let mut names = vec![];
+    for entry in entries {
+        let path = entry
+            .map_err(|e| Error::Io {
+                path: dir.clone(),
+                message: e.to_string(),
+            })?
+            .path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names.sort_unstable();
+
+    names
+        .iter()
+        .map(|name| load_example(year, day, name))
+        .collect()
+}
+
+/// Runs every example of `day` and describes each part whose answer isn't the expected one.
+pub fn check_examples(year: u16, day: u8, solution: &dyn Solution) -> Result<Vec<String>, Error> {
+    let mut failures = vec![];
+    for example in load_examples(year, day)? {
+        let mut parts = example.answers.keys().copied().collect::<Vec<_>>();
+        parts.sort_unstable();
+
+        for part in parts {
+            let expected = example.answers.get(&part);
+            let failure = match solution.solve(part, &example.input, &example.params) {
+                Ok(answer) => match verdict(answer.as_ref(), expected) {
+                    Verdict::Fail { expected } => Some(format!(
+                        "expected\n{}\ngot\n{}",
+                        expected,
+                        answer.map_or("nothing".to_string(), |answer| answer.to_string())
+                    )),
+                    _ => None,
+                },
+                Err(e) => Some(e.to_string()),
+            };
+            if let Some(failure) = failure {
+                failures.push(format!(
+                    "{} day {:02} example \"{}\" part {}: {}",
+                    year, day, example.name, part, failure
+                ));
+            }
+        }
+    }
+    Ok(failures)
+}
+
+/// Test harness behind the `test_examples` test that [`crate::solution`] generates for every
+/// day. `module_path` is the one of the day's module, which ends in `y<year>::day<day>`.
+pub fn assert_examples(module_path: &str, solution: &dyn Solution) {
+    let mut segments = module_path.rsplit("::");
+    let day = segments
+        .next()
+        .and_then(|day| day.strip_prefix("day")?.parse::<u8>().ok());
+    let year = segments
+        .next()
+        .and_then(|year| year.strip_prefix('y')?.parse::<u16>().ok());
+    let (Some(year), Some(day)) = (year, day) else {
+        panic!("{} is not a day module", module_path);
+    };
+
+    match check_examples(year, day, solution) {
+        Ok(failures) if failures.is_empty() => {}
+        Ok(failures) => panic!("{}", failures.join("\n\n")),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_example() {
+        let example = parse_example("example", "1: 26\n2:\n#..\n.#.\nrow: 10\n---\n1,2\n3,4\n");
+        assert_eq!(example.input, "1,2\n3,4\n");
+        assert_eq!(example.answers.get(&1), Some(&"26".to_string()));
+        assert_eq!(example.answers.get(&2), Some(&"#..\n.#.".to_string()));
+        assert_eq!(example.params.get("row", 2_000_000), Ok(10));
+        assert_eq!(example.params.get("max", 4_000_000), Ok(4_000_000));
+
+        let example = parse_example("bare", "1,2\n---x\n");
+        assert_eq!(example.input, "1,2\n---x\n");
+        assert!(example.answers.is_empty());
+        assert!(example.params.is_empty());
+    }
+
+    #[test]
+    fn test_load_examples() {
+        let examples = load_examples(2022, 10).unwrap();
+        assert_eq!(
+            examples
+                .iter()
+                .map(|example| example.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["example", "short"]
+        );
+        assert!(load_examples(2022, 25).unwrap().is_empty());
+        assert!(load_examples(2015, 10).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+/*
+ * Selection of the days `cargo all` runs, e.g. `cargo all 10-15`, `--days 1,3,7` or
+ * `--year 2022`.
+ */
+use crate::days;
+
+/// Parses a comma-separated list of days and inclusive day ranges, e.g. `1,3,10-15`.
+pub fn parse_days(value: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("\"{}\" is not a day between 1 and 25", day.trim())),
+    };
+
+    let mut days = vec![];
+    for item in value.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                if first > last {
+                    return Err(format!("day range \"{}\" is empty", item.trim()));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+/// Parses a comma-separated list of years, which need to have registered days.
+pub fn parse_years(value: &str) -> Result<Vec<u16>, String> {
+    let registered = days::years();
+    let mut years = value
+        .split(',')
+        .map(|year| match year.trim().parse::<u16>() {
+            Ok(year) if registered.contains(&year) => Ok(year),
+            _ => Err(format!(
+                "\"{}\" is not a year with registered days, expected one of {}",
+                year.trim(),
+                registered
+                    .iter()
+                    .map(|year| year.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    years.sort_unstable();
+    years.dedup();
+    Ok(years)
+}
+
+#[derive(Debug, Default)]
+pub struct DayFilter {
+    /// Years to run. All years with registered days run when unset.
+    pub years: Option<Vec<u16>>,
+    /// Days to run. All days run when unset.
+    pub days: Option<Vec<u8>>,
+    /// Leave out the days marked as slow in the registry.
+    pub skip_slow: bool,
+}
+
+impl DayFilter {
+    /// Consumes `--year`, `--days`, `--skip-slow` and an optional free-standing list of days.
+    /// Parse this after all other options, since pico_args expects free arguments last.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let years = args.opt_value_from_fn("--year", parse_years)?;
+        let days = args.opt_value_from_fn("--days", parse_days)?;
+        let skip_slow = args.contains("--skip-slow");
+        let free_days = args.opt_free_from_fn(parse_days)?;
+
+        let days = match (days, free_days) {
+            (Some(mut days), Some(free_days)) => {
+                days.extend(free_days);
+                days.sort_unstable();
+                days.dedup();
+                Some(days)
+            }
+            (days, free_days) => days.or(free_days),
+        };
+        Ok(DayFilter {
+            years,
+            days,
+            skip_slow,
+        })
+    }
+
+    /// The selected years, of the ones with registered days.
+    pub fn years(&self) -> Vec<u16> {
+        days::years()
+            .into_iter()
+            .filter(|year| self.years.as_ref().is_none_or(|years| years.contains(year)))
+            .collect()
+    }
+
+    pub fn is_selected(&self, day: u8) -> bool {
+        self.days.as_ref().is_none_or(|days| days.contains(&day))
+    }
+
+    /// Whether a selected day is left out for being slow.
+    pub fn is_skipped(&self, year: u16, day: u8) -> bool {
+        self.skip_slow && days::get(year, day).is_some_and(|day| day.slow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("10-15"), Ok(vec![10, 11, 12, 13, 14, 15]));
+        assert_eq!(parse_days("7,1,3"), Ok(vec![1, 3, 7]));
+        assert_eq!(parse_days("1-3, 2,25"), Ok(vec![1, 2, 3, 25]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("1,,2").is_err());
+    }
+
+    #[test]
+    fn test_parse_years() {
+        assert_eq!(parse_years("2022, 2022"), Ok(vec![2022]));
+        assert!(parse_years("2014").is_err());
+        assert!(parse_years("22").is_err());
+    }
+
+    #[test]
+    fn test_day_filter() {
+        let filter = DayFilter {
+            years: Some(vec![2022]),
+            days: Some(vec![15, 16, 17]),
+            skip_slow: true,
+        };
+        assert_eq!(filter.years(), vec![2022]);
+        assert!(filter.is_selected(15));
+        assert!(!filter.is_selected(14));
+        assert!(filter.is_skipped(2022, 16));
+        assert!(!filter.is_skipped(2022, 17));
+
+        let filter = DayFilter::default();
+        assert_eq!(filter.years(), days::years());
+        assert!(filter.is_selected(16));
+        assert!(!filter.is_skipped(2022, 16));
+    }
+}
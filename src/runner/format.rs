@@ -0,0 +1,398 @@
+/*
+ * Machine-readable renderings of part results, selected with `--format`.
+ */
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::{PartResult, Stats, Verdict};
+use crate::Answer;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(format!(
+                "unknown format \"{}\", expected one of: text, json, csv",
+                other
+            )),
+        }
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                write!(escaped, "\\u{:04x}", c as u32).unwrap();
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(Answer::Text(text)) => json_string(text),
+        Some(answer) => answer.to_string(),
+        None => "null".to_string(),
+    }
+}
+
+enum Field {
+    Number(Option<u128>),
+    Text(Option<String>),
+    Bool(bool),
+}
+
+/// Optional column groups. A group is included for every record as soon as one of the
+/// results has it, so all records share the same shape.
+struct Columns {
+    bench: bool,
+    check: bool,
+    error: bool,
+    isolation: bool,
+}
+
+impl Columns {
+    fn of(results: &[PartResult]) -> Self {
+        Columns {
+            bench: results.iter().any(|result| result.stats.is_some()),
+            check: results.iter().any(|result| result.verdict.is_some()),
+            error: results.iter().any(|result| result.error.is_some()),
+            isolation: results.iter().any(|result| result.concurrent),
+        }
+    }
+
+    fn keys(&self) -> Vec<&'static str> {
+        let mut keys = vec![];
+        if self.bench {
+            keys.extend([
+                "runs",
+                "min_ns",
+                "median_ns",
+                "mean_ns",
+                "stddev_ns",
+                "parse_median_ns",
+                "solve_median_ns",
+            ]);
+        }
+        if self.check {
+            keys.extend(["check", "expected"]);
+        }
+        if self.error {
+            keys.push("error");
+        }
+        if self.isolation {
+            keys.push("isolated");
+        }
+        keys
+    }
+
+    /// Values for `keys()`. Solving time is estimated as the difference between the part's
+    /// and the parsing step's medians.
+    fn values(&self, result: &PartResult) -> Vec<Field> {
+        let mut values = vec![];
+        if self.bench {
+            let stats = result.stats;
+            let parse_median = result.parse_stats.map(|parse: Stats| parse.median);
+            let nanos = |f: fn(&Stats) -> Duration| stats.map(|s| f(&s).as_nanos());
+
+            values.extend([
+                Field::Number(stats.map(|s| s.runs as u128)),
+                Field::Number(nanos(|s| s.min)),
+                Field::Number(nanos(|s| s.median)),
+                Field::Number(nanos(|s| s.mean)),
+                Field::Number(nanos(|s| s.stddev)),
+                Field::Number(parse_median.map(|d| d.as_nanos())),
+                Field::Number(
+                    stats
+                        .zip(parse_median)
+                        .map(|(s, d)| s.median.saturating_sub(d).as_nanos()),
+                ),
+            ]);
+        }
+        if self.check {
+            let expected = match &result.verdict {
+                Some(Verdict::Fail { expected }) => Some(expected.clone()),
+                _ => None,
+            };
+            values.extend([
+                Field::Text(
+                    result
+                        .verdict
+                        .as_ref()
+                        .map(|verdict| verdict.label().to_lowercase()),
+                ),
+                Field::Text(expected),
+            ]);
+        }
+        if self.error {
+            values.push(Field::Text(
+                result.error.as_ref().map(|error| error.to_string()),
+            ));
+        }
+        if self.isolation {
+            values.push(Field::Bool(!result.concurrent));
+        }
+        values
+    }
+}
+
+/// Renders results as a JSON array with one object per part.
+pub fn to_json(results: &[PartResult]) -> String {
+    let columns = Columns::of(results);
+    let keys = columns.keys();
+
+    let records = results
+        .iter()
+        .map(|result| {
+            let mut record = format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_ns\": {}, \"status\": {}",
+                result.year,
+                result.day,
+                result.part,
+                json_answer(&result.answer),
+                result.elapsed.as_nanos(),
+                json_string(result.status())
+            );
+            for (key, value) in keys.iter().zip(columns.values(result)) {
+                let value = match value {
+                    Field::Number(Some(value)) => value.to_string(),
+                    Field::Text(Some(value)) => json_string(&value),
+                    Field::Bool(value) => value.to_string(),
+                    Field::Number(None) | Field::Text(None) => "null".to_string(),
+                };
+                write!(record, ", \"{}\": {}", key, value).unwrap();
+            }
+            record.push('}');
+            record
+        })
+        .collect::<Vec<_>>();
+
+    if records.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", records.join(",\n"))
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders results as CSV with a header row and one row per part. Benchmark, check, error
+/// and isolation columns are appended when any of the results has them.
+pub fn to_csv(results: &[PartResult]) -> String {
+    let columns = Columns::of(results);
+
+    let mut csv = String::from("year,day,part,answer,elapsed_ns,status");
+    for key in columns.keys() {
+        write!(csv, ",{}", key).unwrap();
+    }
+    csv.push('\n');
+
+    for result in results {
+        let answer = match &result.answer {
+            Some(answer) => csv_field(&answer.to_string()),
+            None => String::new(),
+        };
+        write!(
+            csv,
+            "{},{},{},{},{},{}",
+            result.year,
+            result.day,
+            result.part,
+            answer,
+            result.elapsed.as_nanos(),
+            result.status()
+        )
+        .unwrap();
+
+        for value in columns.values(result) {
+            let value = match value {
+                Field::Number(Some(value)) => value.to_string(),
+                Field::Text(Some(value)) => csv_field(&value),
+                Field::Bool(value) => value.to_string(),
+                Field::Number(None) | Field::Text(None) => String::new(),
+            };
+            write!(csv, ",{}", value).unwrap();
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult::new(
+                2022,
+                1,
+                1,
+                Some(Answer::from(24000_u32)),
+                Duration::from_nanos(1500),
+            ),
+            PartResult::new(
+                2022,
+                10,
+                2,
+                Some(Answer::from(".#.\n\"a,b\"")),
+                Duration::from_nanos(20),
+            ),
+            PartResult::new(2022, 21, 1, None, Duration::ZERO),
+        ]
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&results()),
+            r#"[
+  {"year": 2022, "day": 1, "part": 1, "answer": 24000, "elapsed_ns": 1500, "status": "solved"},
+  {"year": 2022, "day": 10, "part": 2, "answer": ".#.\n\"a,b\"", "elapsed_ns": 20, "status": "solved"},
+  {"year": 2022, "day": 21, "part": 1, "answer": null, "elapsed_ns": 0, "status": "unsolved"}
+]"#
+        );
+        assert_eq!(to_json(&[]), "[]");
+    }
+
+    #[test]
+    fn test_bench_fields() {
+        let stats = Stats {
+            runs: 10,
+            min: Duration::from_nanos(90),
+            median: Duration::from_nanos(100),
+            mean: Duration::from_nanos(110),
+            stddev: Duration::from_nanos(5),
+        };
+        let mut results = results();
+        results[0].stats = Some(stats);
+        results[0].parse_stats = Some(Stats {
+            median: Duration::from_nanos(40),
+            ..stats
+        });
+
+        assert!(to_json(&results).contains(
+            r#""status": "solved", "runs": 10, "min_ns": 90, "median_ns": 100, "mean_ns": 110, "stddev_ns": 5, "parse_median_ns": 40, "solve_median_ns": 60}"#
+        ));
+
+        let csv = to_csv(&results);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("year,day,part,answer,elapsed_ns,status,runs,min_ns,median_ns,mean_ns,stddev_ns,parse_median_ns,solve_median_ns")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("2022,1,1,24000,1500,solved,10,90,100,110,5,40,60")
+        );
+        assert_eq!(csv.lines().last(), Some("2022,21,1,,0,unsolved,,,,,,,"));
+    }
+
+    #[test]
+    fn test_check_fields() {
+        let mut results = results();
+        results[0].verdict = Some(Verdict::Pass);
+        results[1].verdict = Some(Verdict::Fail {
+            expected: "#,#".to_string(),
+        });
+
+        let json = to_json(&results);
+        assert!(json.contains(r#""status": "solved", "check": "pass", "expected": null}"#));
+        assert!(json.contains(r##""status": "solved", "check": "fail", "expected": "#,#"}"##));
+        assert!(json.contains(r#""status": "unsolved", "check": null, "expected": null}"#));
+
+        let csv = to_csv(&results);
+        assert!(csv.starts_with("year,day,part,answer,elapsed_ns,status,check,expected\n"));
+        assert!(csv.contains("2022,1,1,24000,1500,solved,pass,\n"));
+        assert!(csv.contains(",20,solved,fail,\"#,#\"\n"));
+        assert!(csv.ends_with("2022,21,1,,0,unsolved,,\n"));
+    }
+
+    #[test]
+    fn test_error_fields() {
+        let mut results = results();
+        results.push(PartResult::from_outcome(
+            2022,
+            11,
+            1,
+            Err(Error::Parse {
+                line: 7,
+                column: 3,
+                expected: "'Test: divisible by'".to_string(),
+            }),
+            Duration::from_nanos(5),
+        ));
+
+        assert!(to_json(&results).ends_with(
+            r#"{"year": 2022, "day": 11, "part": 1, "answer": null, "elapsed_ns": 5, "status": "error", "error": "parse error at line 7, col 3: expected 'Test: divisible by'"}
+]"#
+        ));
+        assert!(to_csv(&results).ends_with(
+            "2022,11,1,,5,error,\"parse error at line 7, col 3: expected 'Test: divisible by'\"\n"
+        ));
+    }
+
+    #[test]
+    fn test_isolation_field() {
+        let mut results = results();
+        results
+            .iter_mut()
+            .for_each(|result| result.concurrent = true);
+
+        assert!(to_json(&results).contains(r#""status": "solved", "isolated": false}"#));
+        assert!(to_csv(&results).starts_with(
+            "year,day,part,answer,elapsed_ns,status,isolated\n2022,1,1,24000,1500,solved,false\n"
+        ));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&results()),
+            "year,day,part,answer,elapsed_ns,status
+2022,1,1,24000,1500,solved
+2022,10,2,\".#.\n\"\"a,b\"\"\",20,solved
+2022,21,1,,0,unsolved
+"
+        );
+    }
+}
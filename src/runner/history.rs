@@ -0,0 +1,355 @@
+/*
+ * Timing history of `cargo all` runs, used to spot performance regressions between commits.
+ *
+ * The history is a CSV file with one row per solved part and run:
+ *
+ * commit,timestamp,profile,year,day,part,elapsed_ns
+ * 3a78dc6,1670000000,release,2022,1,1,41000
+ *
+ * Rows written before the year column was added have no year and are read as 2022.
+ */
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::PartResult;
+
+const HEADER: &str = "commit,timestamp,profile,year,day,part,elapsed_ns";
+
+/// Year of the rows written before the history recorded one.
+const LEGACY_YEAR: u16 = 2022;
+
+/// Options of `cargo all` for recording and comparing timings.
+#[derive(Debug)]
+pub struct HistoryOptions {
+    /// Append the timings of this run to the history file.
+    pub record: bool,
+    /// Compare the timings of this run with a baseline from the history.
+    pub compare: bool,
+    /// Commit to compare against. Defaults to the most recent run of another commit.
+    pub baseline: Option<String>,
+    /// Slowdown in percent above which a part is flagged.
+    pub threshold: f64,
+}
+
+impl Default for HistoryOptions {
+    fn default() -> Self {
+        HistoryOptions {
+            record: true,
+            compare: false,
+            baseline: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+impl HistoryOptions {
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let record = !args.contains("--no-history");
+        let baseline: Option<String> = args.opt_value_from_str("--baseline")?;
+        let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+        let compare = args.contains("--compare") || baseline.is_some() || threshold.is_some();
+
+        Ok(HistoryOptions {
+            record,
+            compare,
+            baseline,
+            threshold: threshold.unwrap_or(HistoryOptions::default().threshold),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub commit: String,
+    /// Seconds since the unix epoch, shared by all entries of a run.
+    pub timestamp: u64,
+    pub profile: String,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed: Duration,
+}
+
+impl Entry {
+    fn parse(line: &str) -> Option<Self> {
+        let fields = line.split(',').collect::<Vec<_>>();
+        let (commit, timestamp, profile, year, rest) = match fields[..] {
+            [commit, timestamp, profile, ref rest @ ..] if rest.len() == 3 => {
+                (commit, timestamp, profile, LEGACY_YEAR, rest)
+            }
+            [commit, timestamp, profile, year, ref rest @ ..] if rest.len() == 3 => {
+                (commit, timestamp, profile, year.parse().ok()?, rest)
+            }
+            _ => return None,
+        };
+        Some(Entry {
+            commit: commit.to_string(),
+            timestamp: timestamp.parse().ok()?,
+            profile: profile.to_string(),
+            year,
+            day: rest[0].parse().ok()?,
+            part: rest[1].parse().ok()?,
+            elapsed: Duration::from_nanos(rest[2].parse().ok()?),
+        })
+    }
+}
+
+/// The history is kept in the crate root, wherever `cargo all` is run from.
+pub fn history_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("timings.csv")
+}
+
+/// Short hash of the checked out commit, suffixed with `-dirty` when there are uncommitted
+/// changes, since that is usually the case while optimizing a solution.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", commit),
+            _ => commit,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// Timings of debug builds aren't comparable with release ones, so entries record the profile.
+pub fn current_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// Parses the contents of a history file, skipping the header and malformed lines.
+pub fn parse_history(text: &str) -> Vec<Entry> {
+    text.lines().filter_map(Entry::parse).collect()
+}
+
+/// Loads the history file. A missing file is an empty history.
+pub fn load_history() -> Vec<Entry> {
+    match fs::read_to_string(history_path()) {
+        Ok(text) => parse_history(&text),
+        Err(_) => vec![],
+    }
+}
+
+/// Creates history entries for the solved parts in `results`.
+pub fn entries_for_run(commit: &str, results: &[PartResult]) -> Vec<Entry> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+
+    results
+        .iter()
+        .filter(|result| result.answer.is_some())
+        .map(|result| Entry {
+            commit: commit.to_string(),
+            timestamp,
+            profile: current_profile().to_string(),
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            elapsed: result.elapsed,
+        })
+        .collect()
+}
+
+pub fn append_history(entries: &[Entry]) -> io::Result<()> {
+    let path = history_path();
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for entry in entries {
+        writeln!(
+            file,
+            "{},{},{},{},{},{},{}",
+            entry.commit,
+            entry.timestamp,
+            entry.profile,
+            entry.year,
+            entry.day,
+            entry.part,
+            entry.elapsed.as_nanos()
+        )?;
+    }
+    Ok(())
+}
+
+/// Finds the entries of the baseline run: the most recent run of `baseline` if given,
+/// otherwise the most recent run of any commit other than `commit`. Only runs with the
+/// same profile as the current build are considered.
+pub fn find_baseline<'a>(
+    history: &'a [Entry],
+    commit: &str,
+    baseline: Option<&str>,
+) -> Vec<&'a Entry> {
+    let candidates = history.iter().filter(|entry| {
+        entry.profile == current_profile()
+            && match baseline {
+                Some(baseline) => entry.commit == baseline,
+                None => entry.commit != commit,
+            }
+    });
+
+    // later runs of the same commit win, so pick the last one in file order.
+    let Some(latest) = candidates.clone().next_back() else {
+        return vec![];
+    };
+    candidates
+        .filter(|entry| entry.commit == latest.commit && entry.timestamp == latest.timestamp)
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change in percent, positive when the part got slower.
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_nanos() as f64;
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.current.as_nanos() as f64 - baseline) / baseline * 100.0
+    }
+}
+
+/// Pairs the solved parts in `results` with their timing in the baseline run.
+pub fn compare(baseline: &[&Entry], results: &[PartResult]) -> Vec<Comparison> {
+    results
+        .iter()
+        .filter(|result| result.answer.is_some())
+        .filter_map(|result| {
+            let entry = baseline.iter().find(|entry| {
+                (entry.year, entry.day, entry.part) == (result.year, result.day, result.part)
+            })?;
+            Some(Comparison {
+                year: result.year,
+                day: result.day,
+                part: result.part,
+                baseline: entry.elapsed,
+                current: result.elapsed,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Answer;
+
+    fn entry(commit: &str, timestamp: u64, day: u8, part: u8, micros: u64) -> Entry {
+        Entry {
+            commit: commit.to_string(),
+            timestamp,
+            profile: current_profile().to_string(),
+            year: 2022,
+            day,
+            part,
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_parse_history() {
+        let history = parse_history(
+            "commit,timestamp,profile,year,day,part,elapsed_ns\n3a78dc6,1670000000,release,2015,14,2,41000\nbroken line\n",
+        );
+        assert_eq!(
+            history,
+            vec![Entry {
+                commit: "3a78dc6".to_string(),
+                timestamp: 1670000000,
+                profile: "release".to_string(),
+                year: 2015,
+                day: 14,
+                part: 2,
+                elapsed: Duration::from_micros(41),
+            }]
+        );
+
+        let legacy = parse_history("3a78dc6,1670000000,release,14,2,41000\n");
+        assert_eq!((legacy[0].year, legacy[0].day), (LEGACY_YEAR, 14));
+        assert!(parse_history("3a78dc6,1670000000,release,2022,14,2,41000,1\n").is_empty());
+    }
+
+    #[test]
+    fn test_find_baseline() {
+        let history = vec![
+            entry("aaa", 1, 14, 1, 100),
+            entry("aaa", 1, 14, 2, 200),
+            entry("bbb", 2, 14, 1, 90),
+            entry("ccc", 3, 14, 1, 80),
+            entry("ccc", 4, 14, 1, 70),
+        ];
+
+        let baseline = find_baseline(&history, "ccc", None);
+        assert_eq!(baseline, vec![&history[2]]);
+
+        let baseline = find_baseline(&history, "ddd", None);
+        assert_eq!(baseline, vec![&history[4]]);
+
+        let baseline = find_baseline(&history, "ccc", Some("aaa"));
+        assert_eq!(baseline, vec![&history[0], &history[1]]);
+
+        assert!(find_baseline(&history, "ccc", Some("eee")).is_empty());
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = [entry("aaa", 1, 14, 1, 100), entry("aaa", 1, 15, 1, 100)];
+        let baseline = history.iter().collect::<Vec<_>>();
+        let results = vec![
+            PartResult::new(
+                2022,
+                14,
+                1,
+                Some(Answer::from(1_u32)),
+                Duration::from_micros(150),
+            ),
+            PartResult::new(
+                2022,
+                14,
+                2,
+                Some(Answer::from(1_u32)),
+                Duration::from_micros(10),
+            ),
+            PartResult::new(
+                2015,
+                15,
+                1,
+                Some(Answer::from(1_u32)),
+                Duration::from_micros(10),
+            ),
+            PartResult::unsolved(2022, 15, 1),
+        ];
+
+        let comparisons = compare(&baseline, &results);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].change(), 50.0);
+    }
+}
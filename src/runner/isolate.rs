@@ -0,0 +1,114 @@
+/*
+ * Runs solutions on their own thread, so a panicking or hanging part doesn't take the
+ * whole run down with it.
+ */
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Once};
+use std::thread;
+use std::time::Duration;
+
+use crate::Error;
+
+const THREAD_NAME: &str = "solver";
+
+/// Solutions are free to recurse deeply, so give them more than the 2MiB of spawned threads.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+thread_local! {
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Replaces the panic hook with one that keeps the message of panics on solver threads
+/// instead of printing it, so it can be reported with the part that panicked.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(THREAD_NAME) {
+                return default_hook(info);
+            }
+
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".to_string());
+            let message = match info.location() {
+                Some(location) => format!("{} at {}", message, location),
+                None => message,
+            };
+            PANIC_MESSAGE.with(|cell| *cell.borrow_mut() = Some(message));
+        }));
+    });
+}
+
+/// Calls `f` on a separate thread and waits at most `timeout` for it to return.
+///
+/// Panics are turned into [`Error::Panic`]. A thread that times out can't be stopped, so it
+/// is left running in the background until the process exits.
+pub fn run_isolated<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, Error> {
+    install_panic_hook();
+
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name(THREAD_NAME.to_string())
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(f)).map_err(|_| Error::Panic {
+                message: PANIC_MESSAGE
+                    .with(|cell| cell.borrow_mut().take())
+                    .unwrap_or_default(),
+            });
+            // the receiver is gone if the runner stopped waiting.
+            let _ = sender.send(result);
+        });
+    if let Err(e) = spawned {
+        return Err(Error::Panic {
+            message: format!("could not spawn a thread: {}", e),
+        });
+    }
+
+    match timeout {
+        Some(limit) => match receiver.recv_timeout(limit) {
+            Ok(result) => result,
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(Error::Timeout { limit }),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(Error::Panic {
+                message: String::new(),
+            }),
+        },
+        None => receiver.recv().unwrap_or_else(|_| {
+            Err(Error::Panic {
+                message: String::new(),
+            })
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_isolated() {
+        assert_eq!(run_isolated(None, || 42), Ok(42));
+
+        match run_isolated(None, || -> u32 { panic!("no monkeys left") }) {
+            Err(Error::Panic { message }) => {
+                assert!(message.starts_with("no monkeys left at src/runner/isolate.rs:"));
+            }
+            other => panic!("expected a panic, got {:?}", other),
+        }
+
+        let limit = Duration::from_millis(10);
+        assert_eq!(
+            run_isolated(Some(limit), || thread::sleep(Duration::from_secs(5))),
+            Err(Error::Timeout { limit })
+        );
+    }
+}
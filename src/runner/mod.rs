@@ -0,0 +1,447 @@
+/*
+ * This file contains template code.
+ * Runs registered solutions in-process and prints their results.
+ */
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::cache::InputCache;
+use crate::days::{self, Day};
+use crate::{
+    has_custom_input_dir, read_input, try_read_file, Answer, Error, ParamValues, ANSI_BOLD,
+    ANSI_ITALIC, ANSI_RESET,
+};
+
+mod bench;
+mod check;
+pub mod examples;
+mod filter;
+mod format;
+mod history;
+mod isolate;
+mod summary;
+
+pub use bench::{bench_day, BenchOptions, Stats};
+pub use check::{check_day, load_answers, CheckSummary, Verdict};
+pub use examples::{assert_examples, example_path, load_example, load_examples, Example};
+pub use filter::{parse_days, DayFilter};
+pub use format::{to_csv, to_json, Format};
+pub use history::{
+    append_history, compare, current_commit, entries_for_run, find_baseline, load_history,
+    Comparison, HistoryOptions,
+};
+pub use isolate::run_isolated;
+pub use summary::{format_summary, write_readme};
+
+/// Command line options shared by `cargo solve` and `cargo all`.
+#[derive(Debug, Default)]
+pub struct Options {
+    pub format: Format,
+    /// Set when benchmarking with `--bench`, `--runs` or `--budget`.
+    pub bench: Option<BenchOptions>,
+    /// Compare answers with the ones stored in `src/answers`.
+    pub check: bool,
+    /// Time limit per part, after which it's reported as timed out.
+    pub timeout: Option<Duration>,
+    /// Run only this part instead of both.
+    pub part: Option<u8>,
+    /// Puzzle parameters set with `--param`, only accepted by `cargo solve`.
+    pub params: ParamValues,
+}
+
+fn parse_runs(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(0) => Err("need at least one run".to_string()),
+        Ok(runs) => Ok(runs),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Parses a `--param` value of the form `<name>=<value>`.
+fn parse_param(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("\"{}\" is not of the form <name>=<value>", value)),
+    }
+}
+
+/// A part argument, `1` or `2`.
+pub fn parse_part(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("\"{}\" is not a part, expected 1 or 2", value)),
+    }
+}
+
+impl Options {
+    /// Consumes the shared options from `args`, leaving binary-specific ones in place.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let format = args.opt_value_from_str("--format")?.unwrap_or(Format::Text);
+
+        let bench = args.contains("--bench");
+        let runs = args.opt_value_from_fn("--runs", parse_runs)?;
+        let budget_ms: Option<u64> = args.opt_value_from_str("--budget")?;
+        let bench = if bench || runs.is_some() || budget_ms.is_some() {
+            let mut options = BenchOptions {
+                runs,
+                ..Default::default()
+            };
+            if let Some(budget_ms) = budget_ms {
+                options.budget = Duration::from_millis(budget_ms);
+            }
+            Some(options)
+        } else {
+            None
+        };
+
+        let check = args.contains("--check");
+        let timeout = args
+            .opt_value_from_str("--timeout")?
+            .map(Duration::from_secs_f64);
+        let part = args.opt_value_from_fn("--part", parse_part)?;
+
+        Ok(Options {
+            format,
+            bench,
+            check,
+            timeout,
+            part,
+            params: ParamValues::default(),
+        })
+    }
+
+    /// The parts to run for each day.
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+/// Consumes the `[<year>] <day>` arguments of the commands that work on a single day. A lone
+/// day is one of the latest registered year. Parse this after all options.
+pub fn parse_year_and_day(args: &mut pico_args::Arguments) -> Result<(u16, u8), pico_args::Error> {
+    let first: u16 = args.free_from_str()?;
+    let (year, day) = match first {
+        1..=25 => (days::latest_year(), first),
+        _ => (first, args.free_from_str()?),
+    };
+
+    match (year, day) {
+        (days::FIRST_YEAR.., 1..=25) => Ok((year, day as u8)),
+        _ => Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!(
+                "expected [<year>] <day>, with a year since {} and a day between 1 and 25",
+                days::FIRST_YEAR
+            ),
+        }),
+    }
+}
+
+/// Warns on stderr if the input of `day`, read from its usual place, is empty or isn't the
+/// one that was downloaded. Inputs from `AOC_INPUT_DIR` aren't checked, as the downloads
+/// are only those of `src/inputs`.
+pub fn warn_about_input(year: u16, day: u8, input: &str) {
+    if has_custom_input_dir() {
+        return;
+    }
+    if let Some(warning) = InputCache::default().check_input(year, day, input) {
+        eprintln!("{}Warning:{} {}", ANSI_BOLD, ANSI_RESET, warning);
+    }
+}
+
+pub fn exit_with_usage_error(error: pico_args::Error) -> ! {
+    eprintln!("Failed to process arguments: {}", error);
+    process::exit(1);
+}
+
+/// Exits with an error if `args` contains anything that wasn't consumed.
+pub fn finish_args(args: pico_args::Arguments) {
+    let remaining = args.finish();
+    if !remaining.is_empty() {
+        eprintln!("Unexpected arguments: {:?}", remaining);
+        process::exit(1);
+    }
+}
+
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// Time taken by the part, or the median when benchmarking.
+    pub elapsed: Duration,
+    pub stats: Option<Stats>,
+    /// Timings of the day's parsing step, which is included in `elapsed`.
+    pub parse_stats: Option<Stats>,
+    /// Set when checking answers. Parts that weren't run aren't checked.
+    pub verdict: Option<Verdict>,
+    /// Why the part couldn't be solved, e.g. because its input didn't parse.
+    pub error: Option<Error>,
+    /// Set when other days ran at the same time, so the timings aren't isolated.
+    pub concurrent: bool,
+}
+
+impl PartResult {
+    pub fn new(year: u16, day: u8, part: u8, answer: Option<Answer>, elapsed: Duration) -> Self {
+        PartResult {
+            year,
+            day,
+            part,
+            answer,
+            elapsed,
+            stats: None,
+            parse_stats: None,
+            verdict: None,
+            error: None,
+            concurrent: false,
+        }
+    }
+
+    /// Result for a part that returned `outcome` after `elapsed`.
+    pub fn from_outcome(
+        year: u16,
+        day: u8,
+        part: u8,
+        outcome: Result<Option<Answer>, Error>,
+        elapsed: Duration,
+    ) -> Self {
+        match outcome {
+            Ok(answer) => PartResult::new(year, day, part, answer, elapsed),
+            Err(error) => PartResult {
+                error: Some(error),
+                ..PartResult::new(year, day, part, None, elapsed)
+            },
+        }
+    }
+
+    /// Result for a part that wasn't run, e.g. because the day has no solution or input.
+    pub fn unsolved(year: u16, day: u8, part: u8) -> Self {
+        PartResult::new(year, day, part, None, Duration::ZERO)
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self.error {
+            Some(Error::Panic { .. }) => return "panic",
+            Some(Error::Timeout { .. }) => return "timeout",
+            Some(_) => return "error",
+            None => {}
+        }
+
+        if self.answer.is_some() {
+            "solved"
+        } else {
+            "unsolved"
+        }
+    }
+}
+
+/// Solves `part` of `day` on a separate thread, so panics and timeouts only fail the part.
+pub fn run_part(day: &'static Day, part: u8, input: &Arc<str>, options: &Options) -> PartResult {
+    let input = Arc::clone(input);
+    let params = options.params.clone();
+    let timer = Instant::now();
+    let outcome = run_isolated(options.timeout, move || {
+        let timer = Instant::now();
+        let outcome = day.solution.solve(part, &input, &params);
+        (outcome, timer.elapsed())
+    });
+
+    match outcome {
+        Ok((outcome, elapsed)) => {
+            PartResult::from_outcome(day.year, day.day, part, outcome, elapsed)
+        }
+        Err(error) => {
+            PartResult::from_outcome(day.year, day.day, part, Err(error), timer.elapsed())
+        }
+    }
+}
+
+pub fn run_day(day: &'static Day, input: &Arc<str>, options: &Options) -> Vec<PartResult> {
+    options
+        .parts()
+        .into_iter()
+        .map(|part| run_part(day, part, input, options))
+        .collect()
+}
+
+/// Runs the selected parts of `day` once, or benchmarks them if requested in `options`.
+/// Answers are checked against the store when `--check` is set.
+pub fn execute_day(options: &Options, day: &'static Day, input: &str) -> Vec<PartResult> {
+    let input = Arc::from(input);
+    let mut results = match &options.bench {
+        Some(bench) => bench_day(day, &input, bench, options),
+        None => run_day(day, &input, options),
+    };
+    if options.check {
+        check_day(&mut results);
+    }
+    results
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, runs: {}",
+        stats.median, stats.mean, stats.stddev, stats.min, stats.runs
+    )
+}
+
+pub fn print_part(result: &PartResult) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+    if let Some(error) = &result.error {
+        println!(
+            "{} day {:02} part {}: {}",
+            result.year, result.day, result.part, error
+        );
+        return;
+    }
+
+    let isolation = if result.concurrent {
+        ", not isolated"
+    } else {
+        ""
+    };
+
+    match (&result.answer, &result.stats) {
+        (Some(answer), Some(stats)) => {
+            println!(
+                "{} {}({}{}){}",
+                answer,
+                ANSI_ITALIC,
+                format_stats(stats),
+                isolation,
+                ANSI_RESET
+            );
+            if let Some(parse_stats) = &result.parse_stats {
+                println!(
+                    "{}parsing: {}, solving: ~{:.2?}{}",
+                    ANSI_ITALIC,
+                    format_stats(parse_stats),
+                    stats.median.saturating_sub(parse_stats.median),
+                    ANSI_RESET
+                );
+            }
+        }
+        (Some(answer), None) => {
+            println!(
+                "{} {}(elapsed: {:.2?}{}){}",
+                answer, ANSI_ITALIC, result.elapsed, isolation, ANSI_RESET
+            );
+        }
+        (None, _) => {
+            println!("not solved.")
+        }
+    }
+
+    match &result.verdict {
+        Some(Verdict::Fail { expected }) => {
+            println!("{}FAIL{} expected: {}", ANSI_BOLD, ANSI_RESET, expected);
+        }
+        Some(verdict) => println!("{}{}{}", ANSI_BOLD, verdict.label(), ANSI_RESET),
+        None => {}
+    }
+}
+
+/// Exits with a non-zero status if any part returned an error or failed its check, so runs
+/// can gate CI.
+pub fn exit_on_failure(results: &[PartResult]) {
+    let has_errors = results.iter().any(|result| result.error.is_some());
+    if has_errors || CheckSummary::from_results(results).failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Describes how much faster or slower each part got compared with `baseline`, flagging the
+/// parts that slowed down by more than `threshold` percent.
+pub fn format_comparison(baseline: &str, comparisons: &[Comparison], threshold: f64) -> String {
+    let mut text = format!("{}Compared with {}:{}\n", ANSI_BOLD, baseline, ANSI_RESET);
+    for comparison in comparisons {
+        let change = comparison.change();
+        writeln!(
+            text,
+            "{} day {:02} part {}: {:.2?} -> {:.2?} ({:+.1}%){}",
+            comparison.year,
+            comparison.day,
+            comparison.part,
+            comparison.baseline,
+            comparison.current,
+            change,
+            if change > threshold {
+                format!(" {}SLOWER{}", ANSI_BOLD, ANSI_RESET)
+            } else {
+                String::new()
+            }
+        )
+        .unwrap();
+    }
+    text
+}
+
+/// Prints results in one of the machine-readable formats. Text output is printed
+/// incrementally by the callers instead, since it is interleaved with headers.
+pub fn print_structured(format: Format, results: &[PartResult]) {
+    match format {
+        Format::Text => results.iter().for_each(print_part),
+        Format::Json => println!("{}", to_json(results)),
+        Format::Csv => print!("{}", to_csv(results)),
+    }
+}
+
+/// Entry point of `cargo solve [<year>] <day>`: solves both parts against the real input.
+pub fn solve_day() {
+    // cargo passes on the `--` of `cargo solve 2022 17 -- --bench`, as it comes after the
+    // year and day.
+    let mut args = pico_args::Arguments::from_vec(
+        std::env::args_os()
+            .skip(1)
+            .filter(|arg| arg != "--")
+            .collect(),
+    );
+    let mut options = Options::parse(&mut args).unwrap_or_else(|e| exit_with_usage_error(e));
+    let params = args
+        .values_from_fn("--param", parse_param)
+        .unwrap_or_else(|e| exit_with_usage_error(e));
+    for (name, value) in params {
+        options.params.insert(name, value);
+    }
+    let input_path = args
+        .opt_value_from_os_str("--input", |path| Ok::<_, Infallible>(PathBuf::from(path)))
+        .unwrap_or_else(|e| exit_with_usage_error(e));
+    let (year, day) = parse_year_and_day(&mut args).unwrap_or_else(|e| exit_with_usage_error(e));
+    finish_args(args);
+
+    let Some(day) = days::get(year, day) else {
+        eprintln!(
+            "Day {} of {} is not registered in src/days/y{}/mod.rs, scaffold it with `cargo scaffold {} {}`.",
+            day, year, year, year, day
+        );
+        process::exit(1);
+    };
+
+    let input = match &input_path {
+        Some(path) => read_input(path),
+        None => try_read_file("inputs", day.year, day.day),
+    };
+    let input = match input {
+        Ok(input) => {
+            if input_path.is_none() {
+                warn_about_input(day.year, day.day, &input);
+            }
+            input
+        }
+        Err(e) => {
+            eprintln!("{} day {:02}: {}", day.year, day.day, e);
+            process::exit(1);
+        }
+    };
+    let results = execute_day(&options, day, &input);
+    print_structured(options.format, &results);
+    exit_on_failure(&results);
+}
@@ -0,0 +1,287 @@
+/*
+ * Tables summarizing a run: one printed after `cargo all`, and a markdown one kept in the
+ * readme by `--write-readme`.
+ */
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+use std::{fs, io};
+
+use super::PartResult;
+
+pub const README_MARKER: &str = "<!--- benchmarking table --->";
+
+/// Longer answers are cut off, the table is only meant as an overview.
+const MAX_ANSWER_WIDTH: usize = 20;
+
+/// The results of one day, by part.
+struct DayRow<'a> {
+    year: u16,
+    day: u8,
+    parts: [Option<&'a PartResult>; 2],
+}
+
+impl DayRow<'_> {
+    /// The day's status is the one of its first part that wasn't solved, if any.
+    fn status(&self) -> &'static str {
+        self.parts
+            .iter()
+            .flatten()
+            .map(|result| result.status())
+            .find(|&status| status != "solved")
+            .unwrap_or("solved")
+    }
+}
+
+fn rows(results: &[PartResult]) -> Vec<DayRow<'_>> {
+    let mut rows: Vec<DayRow> = vec![];
+    for result in results {
+        let index = usize::from(result.part - 1);
+        match rows.last_mut() {
+            Some(row) if (row.year, row.day) == (result.year, result.day) => {
+                row.parts[index] = Some(result)
+            }
+            _ => {
+                let mut row = DayRow {
+                    year: result.year,
+                    day: result.day,
+                    parts: [None, None],
+                };
+                row.parts[index] = Some(result);
+                rows.push(row);
+            }
+        }
+    }
+    rows
+}
+
+fn answer_cell(result: Option<&PartResult>) -> String {
+    let Some(answer) = result.and_then(|result| result.answer.as_ref()) else {
+        return "-".to_string();
+    };
+
+    let answer = answer.to_string();
+    let first_line = answer.lines().next().unwrap_or_default();
+    if first_line.chars().count() > MAX_ANSWER_WIDTH || first_line.len() < answer.len() {
+        let cut = first_line
+            .chars()
+            .take(MAX_ANSWER_WIDTH - 1)
+            .collect::<String>();
+        format!("{}…", cut)
+    } else {
+        answer
+    }
+}
+
+fn time_cell(result: Option<&PartResult>) -> String {
+    match result {
+        Some(result) if result.answer.is_some() => format!("{:.2?}", result.elapsed),
+        _ => "-".to_string(),
+    }
+}
+
+/// Renders a plain text table with a row per day: the answer and time of each part, and
+/// the day's status.
+pub fn format_summary(results: &[PartResult]) -> String {
+    let header = ["Year", "Day", "Part 1", "Time", "Part 2", "Time", "Status"].map(String::from);
+    let rows = rows(results)
+        .iter()
+        .map(|row| {
+            [
+                row.year.to_string(),
+                format!("{:02}", row.day),
+                answer_cell(row.parts[0]),
+                time_cell(row.parts[0]),
+                answer_cell(row.parts[1]),
+                time_cell(row.parts[1]),
+                row.status().to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String; 7]| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| {
+                let padding = width - cell.chars().count();
+                format!("{}{}", cell, " ".repeat(padding))
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut table = format_row(&header);
+    table.push('\n');
+    table.push_str(
+        &widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<_>>()
+            .join("-+-"),
+    );
+    table.push('\n');
+    for row in &rows {
+        table.push_str(&format_row(row));
+        table.push('\n');
+    }
+    table
+}
+
+/// Renders the markdown table of `--write-readme`, with the timings of each solved part.
+/// Answers are left out, they'd spoil the puzzles.
+pub fn format_readme_table(results: &[PartResult]) -> String {
+    let mut table =
+        String::from("| Year | Day | Part 1 | Part 2 |\n| :---: | :---: | :---: | :---: |\n");
+    let mut total = Duration::ZERO;
+
+    for row in rows(results) {
+        let solved = row
+            .parts
+            .iter()
+            .flatten()
+            .filter(|result| result.answer.is_some())
+            .collect::<Vec<_>>();
+        if solved.is_empty() {
+            continue;
+        }
+        total += solved.iter().map(|result| result.elapsed).sum::<Duration>();
+
+        writeln!(
+            table,
+            "| {0} | [Day {1}](./src/days/y{0}/day{1:02}.rs) | `{2}` | `{3}` |",
+            row.year,
+            row.day,
+            time_cell(row.parts[0]),
+            time_cell(row.parts[1])
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        table,
+        "\n**Total: {:.2}ms**",
+        total.as_secs_f64() * 1000_f64
+    )
+    .unwrap();
+    table
+}
+
+/// Replaces the text between the two benchmark markers in `readme` with `table`.
+pub fn replace_readme_table(readme: &str, table: &str) -> Option<String> {
+    let (before, rest) = readme.split_once(README_MARKER)?;
+    let (_, after) = rest.split_once(README_MARKER)?;
+
+    Some(format!(
+        "{}{}\n{}{}{}",
+        before, README_MARKER, table, README_MARKER, after
+    ))
+}
+
+/// Writes the benchmarks table for `results` into the `README.md` of the crate.
+pub fn write_readme(results: &[PartResult]) -> io::Result<()> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md");
+    let readme = fs::read_to_string(&path)?;
+
+    match replace_readme_table(&readme, &format_readme_table(results)) {
+        Some(readme) => fs::write(&path, readme),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{} needs two \"{}\" lines to put the table between",
+                path.display(),
+                README_MARKER
+            ),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, Error};
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult::new(
+                2022,
+                1,
+                1,
+                Some(Answer::from(24000_u32)),
+                Duration::from_micros(16),
+            ),
+            PartResult::new(
+                2022,
+                1,
+                2,
+                Some(Answer::from(45000_u32)),
+                Duration::from_micros(6),
+            ),
+            PartResult::new(
+                2022,
+                10,
+                2,
+                Some(Answer::from("##..##..##..##..##..##\n###...###")),
+                Duration::from_millis(2),
+            ),
+            PartResult::from_outcome(
+                2022,
+                11,
+                1,
+                Err(Error::Timeout {
+                    limit: Duration::from_secs(1),
+                }),
+                Duration::from_secs(1),
+            ),
+            PartResult::unsolved(2022, 11, 2),
+        ]
+    }
+
+    #[test]
+    fn test_format_summary() {
+        assert_eq!(
+            format_summary(&results()),
+            "\
+Year | Day | Part 1 | Time    | Part 2               | Time   | Status
+-----+-----+--------+---------+----------------------+--------+--------
+2022 | 01  | 24000  | 16.00µs | 45000                | 6.00µs | solved
+2022 | 10  | -      | -       | ##..##..##..##..##.… | 2.00ms | solved
+2022 | 11  | -      | -       | -                    | -      | timeout
+"
+        );
+    }
+
+    #[test]
+    fn test_format_readme_table() {
+        assert_eq!(
+            format_readme_table(&results()),
+            "\
+| Year | Day | Part 1 | Part 2 |
+| :---: | :---: | :---: | :---: |
+| 2022 | [Day 1](./src/days/y2022/day01.rs) | `16.00µs` | `6.00µs` |
+| 2022 | [Day 10](./src/days/y2022/day10.rs) | `-` | `2.00ms` |
+
+**Total: 2.02ms**
+"
+        );
+    }
+
+    #[test]
+    fn test_replace_readme_table() {
+        let readme = format!("# AoC\n{0}\nold\n{0}\n## Usage\n", README_MARKER);
+        assert_eq!(
+            replace_readme_table(&readme, "new\n"),
+            Some(format!("# AoC\n{0}\nnew\n{0}\n## Usage\n", README_MARKER))
+        );
+        assert_eq!(replace_readme_table("# AoC\n", "new\n"), None);
+    }
+}
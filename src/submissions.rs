@@ -0,0 +1,236 @@
+/*
+ * This file contains template code.
+ * Log of submitted answers, so answers known to be wrong are never submitted again.
+ *
+ * The log is a CSV file with one row per attempt. The answer comes last, so it may contain
+ * commas:
+ *
+ * timestamp,year,day,part,outcome,answer
+ * 1670000000,2022,1,1,too-high,71924
+ */
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::cache::format_date;
+use crate::client::SubmitOutcome;
+
+const HEADER: &str = "timestamp,year,day,part,outcome,answer";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: SubmitOutcome,
+    pub answer: String,
+}
+
+impl Submission {
+    /// A submission made just now.
+    pub fn new(year: u16, day: u8, part: u8, answer: &str, outcome: SubmitOutcome) -> Self {
+        Submission {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            year,
+            day,
+            part,
+            outcome,
+            answer: answer.to_string(),
+        }
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(6, ',');
+        Some(Submission {
+            timestamp: fields.next()?.parse().ok()?,
+            year: fields.next()?.parse().ok()?,
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            outcome: SubmitOutcome::from_label(fields.next()?)?,
+            answer: fields.next()?.to_string(),
+        })
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.timestamp,
+            self.year,
+            self.day,
+            self.part,
+            self.outcome.label(),
+            self.answer
+        )
+    }
+}
+
+pub struct SubmissionLog {
+    path: PathBuf,
+}
+
+impl Default for SubmissionLog {
+    fn default() -> Self {
+        SubmissionLog::new(Path::new(env!("CARGO_MANIFEST_DIR")).join(".cache/submissions.csv"))
+    }
+}
+
+impl SubmissionLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        SubmissionLog { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// All logged submissions, skipping malformed lines. A missing log is an empty one.
+    pub fn load(&self) -> io::Result<Vec<Submission>> {
+        match fs::read_to_string(&self.path) {
+            Ok(text) => Ok(text.lines().filter_map(Submission::parse).collect()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn append(&self, submission: &Submission) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        if file.metadata()?.len() == 0 {
+            writeln!(file, "{}", HEADER)?;
+        }
+        writeln!(file, "{}", submission.to_line())
+    }
+}
+
+/// Explains why `answer` isn't worth submitting, judging by the earlier `submissions`: the
+/// part was already solved, the same answer was wrong before, or a number is beyond an
+/// answer that was too high or too low.
+pub fn check_answer(
+    submissions: &[Submission],
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Option<String> {
+    let earlier = submissions
+        .iter()
+        .filter(|submission| {
+            (submission.year, submission.day, submission.part) == (year, day, part)
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(correct) = earlier
+        .iter()
+        .find(|submission| submission.outcome == SubmitOutcome::Correct)
+    {
+        return Some(format!(
+            "part {} was already solved on {} with {}",
+            part,
+            format_date(correct.timestamp),
+            correct.answer
+        ));
+    }
+
+    let number = answer.parse::<i64>().ok();
+    earlier.iter().find_map(|submission| {
+        let date = format_date(submission.timestamp);
+        let bound = submission.answer.parse::<i64>().ok();
+        match (&submission.outcome, number, bound) {
+            (outcome, _, _) if outcome.is_wrong() && submission.answer == answer => Some(format!(
+                "{} was already submitted on {} and was {}",
+                answer,
+                date,
+                outcome.label().replace('-', " ")
+            )),
+            (SubmitOutcome::TooHigh, Some(number), Some(bound)) if number >= bound => {
+                Some(format!(
+                    "{} is too high, {} already was on {}",
+                    answer, submission.answer, date
+                ))
+            }
+            (SubmitOutcome::TooLow, Some(number), Some(bound)) if number <= bound => Some(format!(
+                "{} is too low, {} already was on {}",
+                answer, submission.answer, date
+            )),
+            _ => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn submission(part: u8, answer: &str, outcome: SubmitOutcome) -> Submission {
+        Submission {
+            timestamp: 1_669_870_800,
+            year: 2022,
+            day: 1,
+            part,
+            outcome,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_check_answer() {
+        let submissions = vec![
+            submission(1, "500", SubmitOutcome::TooHigh),
+            submission(1, "100", SubmitOutcome::TooLow),
+            submission(1, "abc", SubmitOutcome::Incorrect),
+            submission(1, "300", SubmitOutcome::RateLimited { wait: None }),
+            submission(2, "42", SubmitOutcome::Correct),
+        ];
+        let check = |part, answer| check_answer(&submissions, 2022, 1, part, answer);
+
+        assert_eq!(check(1, "300"), None);
+        assert_eq!(check(1, "499"), None);
+        assert_eq!(
+            check(1, "500"),
+            Some("500 was already submitted on 2022-12-01 and was too high".to_string())
+        );
+        assert_eq!(
+            check(1, "700"),
+            Some("700 is too high, 500 already was on 2022-12-01".to_string())
+        );
+        assert_eq!(
+            check(1, "-3"),
+            Some("-3 is too low, 100 already was on 2022-12-01".to_string())
+        );
+        assert!(check(1, "abc").is_some());
+        assert_eq!(
+            check(2, "43"),
+            Some("part 2 was already solved on 2022-12-01 with 42".to_string())
+        );
+        assert_eq!(check_answer(&submissions, 2021, 1, 1, "500"), None);
+    }
+
+    #[test]
+    fn test_submission_log() {
+        let path = env::temp_dir().join(format!("aoc-submissions-{}.csv", std::process::id()));
+        let log = SubmissionLog::new(&path);
+        assert_eq!(log.load().unwrap(), vec![]);
+
+        let submissions = vec![
+            submission(1, "500", SubmitOutcome::TooHigh),
+            submission(2, "a,b", SubmitOutcome::Correct),
+        ];
+        for submission in &submissions {
+            log.append(submission).unwrap();
+        }
+        assert_eq!(log.load().unwrap(), submissions);
+        assert!(fs::read_to_string(&path).unwrap().starts_with(HEADER));
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,9 @@
+pub fn part_one(input: &str) -> Option<{{returns}}> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{returns}}> {
+    None
+}
+
+crate::solution!();
@@ -0,0 +1,18 @@
+use petgraph::prelude::UnGraphMap;
+
+/// Reads one edge per line, e.g. `a-b`.
+fn parse(input: &str) -> UnGraphMap<&str, ()> {
+    UnGraphMap::from_edges(input.lines().filter_map(|line| line.split_once('-')))
+}
+
+pub fn part_one(input: &str) -> Option<{{returns}}> {
+    let _graph = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{returns}}> {
+    let _graph = parse(input);
+    None
+}
+
+crate::solution!(parse);
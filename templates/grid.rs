@@ -0,0 +1,18 @@
+use crate::helpers::grid::Grid;
+use crate::Error;
+
+fn parse(input: &str) -> Result<Grid<char>, Error> {
+    Grid::parse(input, "a cell", Some)
+}
+
+pub fn part_one(input: &str) -> Result<Option<{{returns}}>, Error> {
+    let _grid = parse(input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<{{returns}}>, Error> {
+    let _grid = parse(input)?;
+    Ok(None)
+}
+
+crate::solution!(parse);
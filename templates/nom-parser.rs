@@ -0,0 +1,24 @@
+use nom::character::complete::not_line_ending;
+
+use crate::helpers::parse::lines;
+use crate::{run_parser, Error, ParseResult};
+
+fn line(input: &str) -> ParseResult<'_, &str> {
+    not_line_ending(input)
+}
+
+fn parse(input: &str) -> Result<Vec<&str>, Error> {
+    run_parser(lines(line), input)
+}
+
+pub fn part_one(input: &str) -> Result<Option<{{returns}}>, Error> {
+    let _lines = parse(input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<{{returns}}>, Error> {
+    let _lines = parse(input)?;
+    Ok(None)
+}
+
+crate::solution!(parse);